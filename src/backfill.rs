@@ -0,0 +1,248 @@
+use crate::constants::METRICS_PREFIX;
+use crate::helper::{chain_labels, validator_labels};
+use crate::prometheus::Labels;
+use crate::substrate::{tokens_to_i64, Era, SubstrateRPC};
+use crate::utils::Config;
+
+use log::info;
+use std::fmt::Write;
+use std::fs;
+use subxt::utils::AccountId32;
+
+type BackfillResult<T> = Result<T, Box<dyn std::error::Error + Send + Sync>>;
+
+// gauge family written to the openmetrics file
+struct MetricFamily {
+    name: &'static str,
+    help: &'static str,
+    samples: Vec<(Labels, i64, u64)>,
+}
+
+// openmetrics writer grouping samples by metric family
+#[derive(Default)]
+struct OpenMetrics {
+    families: Vec<MetricFamily>,
+}
+impl OpenMetrics {
+    // add a sample with a timestamp in milliseconds
+    fn add(
+        &mut self,
+        name: &'static str,
+        help: &'static str,
+        labels: &Labels,
+        value: i64,
+        ts: u64,
+    ) {
+        let index = match self.families.iter().position(|f| f.name == name) {
+            Some(index) => index,
+            None => {
+                self.families.push(MetricFamily {
+                    name,
+                    help,
+                    samples: vec![],
+                });
+                self.families.len() - 1
+            }
+        };
+        self.families[index]
+            .samples
+            .push((labels.clone(), value, ts));
+    }
+
+    fn encode(&self) -> String {
+        let mut out = String::new();
+        for family in self.families.iter() {
+            let name = format!("{METRICS_PREFIX}_{}", family.name);
+            let _ = writeln!(out, "# HELP {name} {}.", family.help);
+            let _ = writeln!(out, "# TYPE {name} gauge");
+            for (labels, value, ts) in family.samples.iter() {
+                let _ = writeln!(
+                    out,
+                    "{name}{} {value} {}.{:03}",
+                    encode_labels(labels),
+                    ts / 1000,
+                    ts % 1000
+                );
+            }
+        }
+        out.push_str("# EOF\n");
+        out
+    }
+}
+
+// encode labels the same way as the live /metrics endpoint
+fn encode_labels(labels: &Labels) -> String {
+    let escape = |v: &str| {
+        v.replace('\\', "\\\\")
+            .replace('"', "\\\"")
+            .replace('\n', "\\n")
+    };
+    format!(
+        "{{network=\"{}\",chain=\"{}\",validator_name=\"{}\",validator_address=\"{}\"}}",
+        escape(&labels.network),
+        escape(&labels.chain),
+        escape(labels.validator_name.as_deref().unwrap_or_default()),
+        escape(labels.validator_address.as_deref().unwrap_or_default())
+    )
+}
+
+// find the last block of the given era, searching from the given block onwards
+async fn find_era_end(rpc: &SubstrateRPC, era: Era, from: u32, latest: u32) -> BackfillResult<u32> {
+    let (mut lo, mut hi) = (from, latest);
+    let mut next_era_start = None;
+
+    // binary search the first block of a later era
+    while lo <= hi {
+        let mid = lo + (hi - lo) / 2;
+        let hash = rpc
+            .get_block_hash(mid)
+            .await
+            .ok_or(format!("Unable to fetch hash of block {mid}"))?;
+        // blocks before staking started have no active era yet
+        let active_era = rpc
+            .at_block(hash)
+            .get_active_era_entry()
+            .await
+            .ok_or(format!("Unable to fetch active era at block {mid}"))?
+            .unwrap_or(0);
+
+        if active_era > era {
+            next_era_start = Some(mid);
+            match mid.checked_sub(1) {
+                Some(prev) => hi = prev,
+                None => break,
+            }
+        } else {
+            lo = mid + 1;
+        }
+    }
+
+    match next_era_start {
+        Some(block) if block > 0 => Ok(block - 1),
+        _ => Err(format!("Era {era} has not ended yet").into()),
+    }
+}
+
+// write the metrics of the given (inclusive) era range to an openmetrics file
+pub async fn backfill(
+    config: &Config,
+    archive_url: &str,
+    from_era: Era,
+    to_era: Era,
+    output: &str,
+) -> BackfillResult<()> {
+    let rpc = SubstrateRPC::new(config.network.clone(), archive_url).await?;
+    let latest = rpc
+        .get_finalized_block_number()
+        .await
+        .ok_or("Unable to fetch finalized block")?;
+
+    let labels = chain_labels(config);
+    let mut metrics = OpenMetrics::default();
+    let mut from_block = 1;
+
+    for era in from_era..=to_era {
+        let end = find_era_end(&rpc, era, from_block, latest).await?;
+        from_block = end + 1;
+
+        let hash = rpc
+            .get_block_hash(end)
+            .await
+            .ok_or(format!("Unable to fetch hash of block {end}"))?;
+        let at = rpc.at_block(hash);
+        let ts = at
+            .get_timestamp()
+            .await
+            .ok_or(format!("Unable to fetch timestamp of block {end}"))?;
+        info!("Backfilling era {era} at block {end}");
+
+        let era_points = at.get_all_era_points(era).await.unwrap_or_default();
+        let active_count = era_points.individual.len() as i64;
+
+        metrics.add("era", "Current active era", &labels, era.into(), ts);
+        if let Some(min) = at.get_minimum_active_stake().await {
+            metrics.add(
+                "minimum_active_stake",
+                "The minimum active nominator stake of the last successful election",
+                &labels,
+                tokens_to_i64(min),
+                ts,
+            );
+        }
+        if let Some(total) = at.get_total_stake(era).await {
+            if active_count > 0 {
+                metrics.add(
+                    "average_stake",
+                    "The average amount staked till the current era",
+                    &labels,
+                    tokens_to_i64(total) / active_count,
+                    ts,
+                );
+            }
+        }
+
+        for validator in config.validators.iter() {
+            let validator_labels = validator_labels(config, validator);
+            let Ok(account_id) = validator.address.parse::<AccountId32>() else {
+                continue;
+            };
+
+            let points = era_points
+                .individual
+                .iter()
+                .find(|(id, _)| id == &account_id)
+                .map(|(_, p)| *p);
+            let summary = at
+                .get_nominator_summary(era, &account_id)
+                .await
+                .unwrap_or_default();
+
+            metrics.add(
+                "active",
+                "Whether the validator is in the active set",
+                &validator_labels,
+                points.is_some().into(),
+                ts,
+            );
+            metrics.add(
+                "era_points",
+                "Era points earned since the current era started",
+                &validator_labels,
+                points.unwrap_or_default(),
+                ts,
+            );
+            metrics.add(
+                "nominator_stake",
+                "Total amount staked by nominators",
+                &validator_labels,
+                tokens_to_i64(summary.total),
+                ts,
+            );
+            metrics.add(
+                "nominator_count",
+                "Total number of nominators",
+                &validator_labels,
+                summary.nominator_count.into(),
+                ts,
+            );
+            metrics.add(
+                "own_stake",
+                "Amount staked by the validator itself",
+                &validator_labels,
+                tokens_to_i64(summary.own),
+                ts,
+            );
+            metrics.add(
+                "exposure_page_count",
+                "Number of pages the nominator exposure is split into",
+                &validator_labels,
+                summary.page_count.into(),
+                ts,
+            );
+        }
+    }
+
+    fs::write(output, metrics.encode())?;
+    info!("Wrote eras {from_era} to {to_era} to {output}");
+    Ok(())
+}
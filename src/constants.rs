@@ -14,5 +14,16 @@ pub static POLKADOT_ADDR_PREFIX: u8 = 0;
 pub static KUSAMA_ADDR_PREFIX: u8 = 2;
 pub static AVAIL_ADDR_PREFIX: u8 = 42;
 
+// token decimals
+pub static POLKADOT_DECIMALS: u32 = 10;
+pub static KUSAMA_DECIMALS: u32 = 12;
+pub static AVAIL_DECIMALS: u32 = 18;
+
 // prometheus metrics prefix
 pub static METRICS_PREFIX: &str = "substratheus";
+
+// number of completed eras kept in history by default
+pub static DEFAULT_HISTORY_ERAS: u32 = 28;
+
+// number of payout pages submitted per batch_all by default
+pub static DEFAULT_PAYOUT_BATCH_SIZE: usize = 8;
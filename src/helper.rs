@@ -1,11 +1,34 @@
 use crate::http::State;
-use crate::prometheus::Labels;
+use crate::prometheus::{BackingLabels, Labels};
 use crate::substrate::SubstrateRPC;
+use crate::utils::{Config, Validator};
 
 use async_std::task;
 use std::sync::Arc;
 use std::time::Duration;
 
+// chain-level labels
+pub fn chain_labels(config: &Config) -> Labels {
+    Labels {
+        network: serde_yaml::to_string(&config.network)
+            .unwrap()
+            .trim()
+            .into(),
+        chain: config.chain.clone(),
+        validator_name: None,
+        validator_address: None,
+    }
+}
+
+// validator-level labels
+pub fn validator_labels(config: &Config, validator: &Validator) -> Labels {
+    Labels {
+        validator_name: Some(validator.name.clone()),
+        validator_address: Some(validator.address.clone()),
+        ..chain_labels(config)
+    }
+}
+
 pub fn initialize_metrics(state: &State) {
     // chain-level labels
     let chain_labels = Labels {
@@ -20,11 +43,42 @@ pub fn initialize_metrics(state: &State) {
 
     // initialize chain metrics
     state.metrics.era.get_or_create(&chain_labels).set(0);
+    state
+        .metrics
+        .planned_era
+        .get_or_create(&chain_labels)
+        .set(0);
+    state
+        .metrics
+        .history_eras
+        .get_or_create(&chain_labels)
+        .set(0);
+    for metric in [
+        &state.metrics.era_points_min,
+        &state.metrics.era_points_p10,
+        &state.metrics.era_points_median,
+        &state.metrics.era_points_p90,
+        &state.metrics.era_points_max,
+    ] {
+        metric.get_or_create(&chain_labels).set(0.0);
+    }
     state
         .metrics
         .asset_hub_rpc_health
         .get_or_create(&chain_labels)
         .set(0);
+    state
+        .metrics
+        .payout_fees
+        .get_or_create(&chain_labels)
+        .inc_by(0.0);
+    if state.config.relay_rpc_url.is_some() {
+        state
+            .metrics
+            .relay_rpc_health
+            .get_or_create(&chain_labels)
+            .set(0);
+    }
     state
         .metrics
         .minimum_active_stake
@@ -35,6 +89,61 @@ pub fn initialize_metrics(state: &State) {
         .average_stake
         .get_or_create(&chain_labels)
         .set(0);
+    state
+        .metrics
+        .active_set_size
+        .get_or_create(&chain_labels)
+        .set(0);
+    state
+        .metrics
+        .lowest_elected_backing
+        .get_or_create(&chain_labels)
+        .set(0);
+    state
+        .metrics
+        .election_emergency
+        .get_or_create(&chain_labels)
+        .set(0);
+    state
+        .metrics
+        .election_snapshot_voters
+        .get_or_create(&chain_labels)
+        .set(0);
+    state
+        .metrics
+        .election_snapshot_targets
+        .get_or_create(&chain_labels)
+        .set(0);
+    state
+        .metrics
+        .queued_solution_minimal_stake
+        .get_or_create(&chain_labels)
+        .set(0);
+    state
+        .metrics
+        .queued_solution_sum_stake
+        .get_or_create(&chain_labels)
+        .set(0);
+    state
+        .metrics
+        .signed_submissions
+        .get_or_create(&chain_labels)
+        .set(0);
+    state
+        .metrics
+        .era_start_timestamp
+        .get_or_create(&chain_labels)
+        .set(0);
+    state
+        .metrics
+        .era_elapsed_seconds
+        .get_or_create(&chain_labels)
+        .set(0);
+    state
+        .metrics
+        .era_progress
+        .get_or_create(&chain_labels)
+        .set(0.0);
 
     // validator-level metrics
     for v in state.config.validators.iter() {
@@ -56,11 +165,128 @@ pub fn initialize_metrics(state: &State) {
             .nominator_stake
             .get_or_create(&validator_labels)
             .set(0);
+        state
+            .metrics
+            .active_last_era
+            .get_or_create(&validator_labels)
+            .set(0);
+        state
+            .metrics
+            .era_points_last_era
+            .get_or_create(&validator_labels)
+            .set(0);
+        state
+            .metrics
+            .nominator_stake_last_era
+            .get_or_create(&validator_labels)
+            .set(0);
         state
             .metrics
             .nominator_count
             .get_or_create(&validator_labels)
             .set(0);
+        state
+            .metrics
+            .own_stake
+            .get_or_create(&validator_labels)
+            .set(0);
+        state
+            .metrics
+            .exposure_page_count
+            .get_or_create(&validator_labels)
+            .set(0);
+        state
+            .metrics
+            .nominators_over_page_size
+            .get_or_create(&validator_labels)
+            .set(0);
+        state
+            .metrics
+            .oversubscribed
+            .get_or_create(&validator_labels)
+            .set(0);
+
+        state
+            .metrics
+            .history_eras_active
+            .get_or_create(&validator_labels)
+            .set(0);
+        state
+            .metrics
+            .history_average_era_points
+            .get_or_create(&validator_labels)
+            .set(0.0);
+        state
+            .metrics
+            .history_average_backing
+            .get_or_create(&validator_labels)
+            .set(0);
+        for metric in [
+            &state.metrics.estimated_payout,
+            &state.metrics.commission_payout,
+            &state.metrics.nominator_payout,
+        ] {
+            metric.get_or_create(&validator_labels).set(0);
+        }
+        state
+            .metrics
+            .unclaimed_eras
+            .get_or_create(&validator_labels)
+            .set(0);
+        for metric in [
+            &state.metrics.pending_slashes,
+            &state.metrics.pending_slash_amount,
+            &state.metrics.slashed_amount,
+            &state.metrics.slashing_span_index,
+        ] {
+            metric.get_or_create(&validator_labels).set(0);
+        }
+        if v.node_rpc_url.is_some() {
+            for metric in [
+                &state.metrics.node_rpc_health,
+                &state.metrics.node_peers,
+                &state.metrics.node_syncing,
+            ] {
+                metric.get_or_create(&validator_labels).set(0);
+            }
+        }
+        state
+            .metrics
+            .payouts_succeeded
+            .get_or_create(&validator_labels)
+            .inc_by(0);
+        state
+            .metrics
+            .payouts_failed
+            .get_or_create(&validator_labels)
+            .inc_by(0);
+        state
+            .metrics
+            .rank_by_backing
+            .get_or_create(&validator_labels)
+            .set(0);
+        state
+            .metrics
+            .backing_margin
+            .get_or_create(&validator_labels)
+            .set(0);
+
+        for backing in ["active", "inactive"] {
+            let backing_labels = BackingLabels {
+                backing: backing.into(),
+                labels: validator_labels.clone(),
+            };
+            state
+                .metrics
+                .nominations_count
+                .get_or_create(&backing_labels)
+                .set(0);
+            state
+                .metrics
+                .nominations_stake
+                .get_or_create(&backing_labels)
+                .set(0);
+        }
     }
 }
 
@@ -147,3 +373,87 @@ pub async fn rpc_manager(state: State) {
         }
     }
 }
+
+// relay chain rpc holding the session state of the validators, the staking rpc is used
+// when no relay chain is configured; None unless the connected runtime runs babe
+pub async fn relay_rpc(state: &State) -> Option<Arc<SubstrateRPC>> {
+    let rpc = match state.config.relay_rpc_url {
+        Some(_) => state.relay_rpc.read().await.clone(),
+        None => state.rpc.read().await.clone(),
+    }?;
+    rpc.has_pallet("Babe").then_some(rpc)
+}
+
+pub async fn relay_rpc_manager(state: State) {
+    let Some(rpc_url) = state.config.relay_rpc_url.clone() else {
+        return;
+    };
+    let chain_labels = chain_labels(&state.config);
+
+    loop {
+        if *state.shutdown.read().await {
+            log::info!("relay_rpc_manager shutting down");
+            break;
+        }
+
+        log::info!("Connecting to relay RPC: {}", rpc_url);
+
+        match SubstrateRPC::new(state.config.network.clone(), &rpc_url).await {
+            Ok(rpc) => {
+                {
+                    let mut guard = state.relay_rpc.write().await;
+                    *guard = Some(Arc::new(rpc));
+                }
+
+                log::info!("Relay RPC connected: {}", rpc_url);
+                state
+                    .metrics
+                    .relay_rpc_health
+                    .get_or_create(&chain_labels)
+                    .set(1);
+                // stay alive until RPC fails
+                loop {
+                    task::sleep(Duration::from_secs(10)).await;
+
+                    let healthy = {
+                        let guard = state.relay_rpc.read().await;
+                        match guard.as_ref() {
+                            Some(rpc) => rpc.get_finalized_block_number().await.is_some(),
+                            None => false,
+                        }
+                    };
+
+                    if !healthy {
+                        log::warn!("Relay RPC unhealthy: {}", rpc_url);
+                        state
+                            .metrics
+                            .relay_rpc_health
+                            .get_or_create(&chain_labels)
+                            .set(0);
+                        break;
+                    }
+                }
+
+                // drop RPC
+                {
+                    let mut guard = state.relay_rpc.write().await;
+                    *guard = None;
+                }
+            }
+
+            Err(e) => {
+                log::warn!(
+                    "Relay RPC connection failed ({}): {}, retrying...",
+                    rpc_url,
+                    e
+                );
+                state
+                    .metrics
+                    .relay_rpc_health
+                    .get_or_create(&chain_labels)
+                    .set(0);
+                task::sleep(Duration::from_secs(5)).await;
+            }
+        }
+    }
+}
@@ -0,0 +1,94 @@
+use crate::rewards;
+use crate::substrate::{Era, EraPoints, SubstrateRPC, Tokens};
+use crate::utils::Validator;
+
+use std::collections::BTreeMap;
+use subxt::utils::AccountId32;
+
+// results of a tracked validator in a completed era
+#[derive(Debug, Clone, Default)]
+pub struct ValidatorEraRecord {
+    pub active: bool,
+    pub points: EraPoints,
+    pub rank: u32,
+    pub backing: Tokens,
+    pub own_stake: Tokens,
+    pub nominator_count: u32,
+    pub commission: u32,
+}
+
+impl ValidatorEraRecord {
+    // share of the era's validator reward earned through era points
+    pub fn reward(&self, era: &EraRecord) -> Tokens {
+        rewards::points_share(era.validator_reward, self.points, era.total_points)
+    }
+}
+
+// results of a completed era, keyed by validator address
+#[derive(Debug, Clone, Default)]
+pub struct EraRecord {
+    pub era: Era,
+    pub total_points: EraPoints,
+    pub validator_reward: Tokens,
+    pub validators: BTreeMap<String, ValidatorEraRecord>,
+}
+
+// per-era records of the tracked validators, keyed by era
+pub type History = BTreeMap<Era, EraRecord>;
+
+// fetch the results of the tracked validators in a completed era
+pub async fn fetch_era_record(
+    rpc: &SubstrateRPC,
+    era: Era,
+    validators: &[Validator],
+) -> Option<EraRecord> {
+    let points = rpc.get_all_era_points(era).await?;
+    let validator_reward = rpc.get_validator_reward(era).await.unwrap_or_default();
+
+    let mut record = EraRecord {
+        era,
+        total_points: points.total,
+        validator_reward,
+        validators: BTreeMap::new(),
+    };
+
+    for validator in validators {
+        let Ok(account_id) = validator.address.parse::<AccountId32>() else {
+            continue;
+        };
+
+        let era_points = points
+            .individual
+            .iter()
+            .find(|(id, _)| id == &account_id)
+            .map(|(_, p)| *p);
+        // rank by era points within the active set, 0 if not active
+        let rank = era_points.map_or(0, |own| {
+            1 + points.individual.iter().filter(|(_, p)| *p > own).count() as u32
+        });
+        // failed queries leave the era to be fetched again, missing entries count as 0
+        let summary = rpc
+            .get_elected_summary(era, &account_id)
+            .await?
+            .unwrap_or_default();
+        let commission = rpc
+            .get_validator_commission(era, &account_id)
+            .await?
+            .unwrap_or_default();
+
+        record.validators.insert(
+            validator.address.clone(),
+            ValidatorEraRecord {
+                active: era_points.is_some(),
+                points: era_points.unwrap_or_default(),
+                rank,
+                backing: summary.total,
+                own_stake: summary.own,
+                nominator_count: summary.nominator_count,
+                commission,
+            },
+        );
+    }
+
+    Some(record)
+}
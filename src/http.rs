@@ -1,11 +1,14 @@
 use async_std::sync::RwLock;
 use prometheus_client::encoding::text::encode;
 use prometheus_client::registry::Registry;
+use serde::Deserialize;
 use std::sync::Arc;
-use tide::{Request, Response};
+use tide::{Body, Request, Response};
 
+use crate::history::History;
 use crate::prometheus::Metrics;
-use crate::substrate::SubstrateRPC;
+use crate::store::Store;
+use crate::substrate::{Era, NominationIndex, SubstrateRPC, UnclaimedPayouts};
 use crate::utils::Config;
 
 #[derive(Clone)]
@@ -14,6 +17,11 @@ pub struct State {
     pub registry: Arc<Registry>,
     pub metrics: Arc<Metrics>,
     pub rpc: Arc<RwLock<Option<Arc<SubstrateRPC>>>>,
+    pub relay_rpc: Arc<RwLock<Option<Arc<SubstrateRPC>>>>,
+    pub nominations: Arc<RwLock<Option<(Era, NominationIndex)>>>,
+    pub unclaimed: Arc<RwLock<UnclaimedPayouts>>,
+    pub history: Arc<RwLock<History>>,
+    pub store: Option<Arc<Store>>,
     pub shutdown: Arc<RwLock<bool>>,
 }
 // fetch all metrics
@@ -26,3 +34,80 @@ pub async fn handle_metrics(req: Request<State>) -> tide::Result {
         .content_type(tide::http::mime::PLAIN)
         .build())
 }
+
+// era range query format
+#[derive(Deserialize, Debug)]
+struct EraRange {
+    from: Option<Era>,
+    to: Option<Era>,
+}
+
+// fetch the stored per-era results of a validator
+pub async fn handle_validator_eras(req: Request<State>) -> tide::Result {
+    let state = req.state();
+    let Some(store) = state.store.as_ref() else {
+        return Err(tide::Error::from_str(
+            404,
+            "Historical store is not configured",
+        ));
+    };
+
+    let address = req.param("address")?;
+    let range: EraRange = req.query()?;
+    let entries = store
+        .validator_eras(
+            address,
+            range.from.unwrap_or(0),
+            range.to.unwrap_or(Era::MAX),
+        )
+        .map_err(|e| tide::Error::from_str(500, e.to_string()))?;
+
+    Ok(Response::builder(200)
+        .body(Body::from_json(&entries)?)
+        .build())
+}
+
+// fetch the stored rewards paid out for a validator and its nominators
+pub async fn handle_validator_rewards(req: Request<State>) -> tide::Result {
+    let state = req.state();
+    let Some(store) = state.store.as_ref() else {
+        return Err(tide::Error::from_str(
+            404,
+            "Historical store is not configured",
+        ));
+    };
+
+    let address = req.param("address")?;
+    let range: EraRange = req.query()?;
+    let entries = store
+        .validator_rewards(
+            address,
+            range.from.unwrap_or(0),
+            range.to.unwrap_or(Era::MAX),
+        )
+        .map_err(|e| tide::Error::from_str(500, e.to_string()))?;
+
+    Ok(Response::builder(200)
+        .body(Body::from_json(&entries)?)
+        .build())
+}
+
+// fetch the stored slashing and offence occurrences of a validator
+pub async fn handle_validator_slashing(req: Request<State>) -> tide::Result {
+    let state = req.state();
+    let Some(store) = state.store.as_ref() else {
+        return Err(tide::Error::from_str(
+            404,
+            "Historical store is not configured",
+        ));
+    };
+
+    let address = req.param("address")?;
+    let entries = store
+        .validator_slashing(address)
+        .map_err(|e| tide::Error::from_str(500, e.to_string()))?;
+
+    Ok(Response::builder(200)
+        .body(Body::from_json(&entries)?)
+        .build())
+}
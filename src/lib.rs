@@ -1,7 +1,17 @@
+pub mod backfill;
 pub mod constants;
 pub mod helper;
+pub mod history;
 pub mod http;
+pub mod node;
+pub mod payouts;
+pub mod phragmen;
 pub mod prometheus;
+pub mod report;
+pub mod rewards;
+pub mod slots;
+pub mod stats;
+pub mod store;
 pub mod substrate;
 pub mod utils;
 pub mod workers;
@@ -1,8 +1,13 @@
 use substratheus::constants::METRICS_PREFIX;
-use substratheus::helper::{initialize_metrics, rpc_manager};
-use substratheus::http::{handle_metrics, State};
+use substratheus::helper::{initialize_metrics, relay_rpc_manager, rpc_manager};
+use substratheus::http::{
+    handle_metrics, handle_validator_eras, handle_validator_rewards, handle_validator_slashing,
+    State,
+};
+use substratheus::payouts::load_signer;
 use substratheus::prometheus::Metrics;
-use substratheus::utils::{Args, Config};
+use substratheus::store::Store;
+use substratheus::utils::{Args, Command, Config};
 
 use async_ctrlc::CtrlC;
 use async_std::{sync::RwLock, task};
@@ -18,6 +23,38 @@ async fn main() -> tide::Result<()> {
     // load config
     let config = Config::load(&args.config).expect("Unable to parse config file!");
 
+    // run one-off subcommands
+    if let Some(command) = args.command.clone() {
+        tide::log::start();
+
+        match command {
+            Command::Backfill {
+                from_era,
+                to_era,
+                archive_url,
+                output,
+            } => {
+                let archive_url = archive_url.unwrap_or(config.rpc_url.clone());
+                substratheus::backfill::backfill(&config, &archive_url, from_era, to_era, &output)
+                    .await
+                    .map_err(|e| tide::Error::from_str(500, e.to_string()))?;
+            }
+            Command::Report {
+                from_era,
+                to_era,
+                output,
+            } => {
+                let output = output
+                    .or(config.report_dir.clone())
+                    .unwrap_or(String::from("reports"));
+                substratheus::report::report(&config, from_era, to_era, &output)
+                    .await
+                    .map_err(|e| tide::Error::from_str(500, e.to_string()))?;
+            }
+        }
+        return Ok(());
+    }
+
     // initialize registry
     let mut registry = Registry::default();
 
@@ -25,9 +62,14 @@ async fn main() -> tide::Result<()> {
     let metrics = Metrics::default();
     registry.register(
         format!("{METRICS_PREFIX}_era"),
-        "Current era",
+        "Current active era",
         metrics.era.clone(),
     );
+    registry.register(
+        format!("{METRICS_PREFIX}_planned_era"),
+        "Latest planned era, ahead of the active era once the next era is elected",
+        metrics.planned_era.clone(),
+    );
     registry.register(
         format!("{METRICS_PREFIX}_active"),
         "Whether the validator is in the active set",
@@ -38,6 +80,96 @@ async fn main() -> tide::Result<()> {
         "Era points earned since the current era started",
         metrics.era_points.clone(),
     );
+    registry.register(
+        format!("{METRICS_PREFIX}_active_last_era"),
+        "Whether the validator was in the active set of the last completed era",
+        metrics.active_last_era.clone(),
+    );
+    registry.register(
+        format!("{METRICS_PREFIX}_era_points_last_era"),
+        "Final era points earned in the last completed era",
+        metrics.era_points_last_era.clone(),
+    );
+    registry.register(
+        format!("{METRICS_PREFIX}_nominator_stake_last_era"),
+        "Total amount staked by nominators in the last completed era",
+        metrics.nominator_stake_last_era.clone(),
+    );
+    registry.register(
+        format!("{METRICS_PREFIX}_era_points_percentile"),
+        "Percentile rank of the validator's era points within the active set",
+        metrics.era_points_percentile.clone(),
+    );
+    registry.register(
+        format!("{METRICS_PREFIX}_era_points_median_ratio"),
+        "Ratio of the validator's era points to the active set median",
+        metrics.era_points_median_ratio.clone(),
+    );
+    registry.register(
+        format!("{METRICS_PREFIX}_era_points_mean_ratio"),
+        "Ratio of the validator's era points to the active set mean",
+        metrics.era_points_mean_ratio.clone(),
+    );
+    registry.register(
+        format!("{METRICS_PREFIX}_era_points_zscore"),
+        "Z-score of the validator's era points within the active set",
+        metrics.era_points_zscore.clone(),
+    );
+    registry.register(
+        format!("{METRICS_PREFIX}_era_points_min"),
+        "Lowest era points in the active set",
+        metrics.era_points_min.clone(),
+    );
+    registry.register(
+        format!("{METRICS_PREFIX}_era_points_p10"),
+        "10th percentile of era points in the active set",
+        metrics.era_points_p10.clone(),
+    );
+    registry.register(
+        format!("{METRICS_PREFIX}_era_points_median"),
+        "Median era points in the active set",
+        metrics.era_points_median.clone(),
+    );
+    registry.register(
+        format!("{METRICS_PREFIX}_era_points_p90"),
+        "90th percentile of era points in the active set",
+        metrics.era_points_p90.clone(),
+    );
+    registry.register(
+        format!("{METRICS_PREFIX}_era_points_max"),
+        "Highest era points in the active set",
+        metrics.era_points_max.clone(),
+    );
+    registry.register(
+        format!("{METRICS_PREFIX}_era_points_per_hour"),
+        "Era points earned per hour over the last hour",
+        metrics.era_points_per_hour.clone(),
+    );
+    registry.register(
+        format!("{METRICS_PREFIX}_era_points_per_block"),
+        "Era points earned per block over the last hour",
+        metrics.era_points_per_block.clone(),
+    );
+    registry.register(
+        format!("{METRICS_PREFIX}_session_era_points"),
+        "Era points earned since the current session started",
+        metrics.session_era_points.clone(),
+    );
+    registry.register(
+        format!("{METRICS_PREFIX}_projected_era_points"),
+        "Era points projected at the end of the era from the current rate",
+        metrics.projected_era_points.clone(),
+    );
+    registry.register(
+        format!("{METRICS_PREFIX}_median_era_points_per_hour"),
+        "Median era points earned per hour by the active set since the era started",
+        metrics.median_era_points_per_hour.clone(),
+    );
+    registry.register(
+        format!("{METRICS_PREFIX}_projected_median_era_points"),
+        "Median era points of the active set projected at the end of the era",
+        metrics.projected_median_era_points.clone(),
+    );
     registry.register(
         format!("{METRICS_PREFIX}_nominator_stake"),
         "Total amount staked by nominators",
@@ -48,6 +180,41 @@ async fn main() -> tide::Result<()> {
         "Total number of nominators",
         metrics.nominator_count.clone(),
     );
+    registry.register(
+        format!("{METRICS_PREFIX}_own_stake"),
+        "Amount staked by the validator itself",
+        metrics.own_stake.clone(),
+    );
+    registry.register(
+        format!("{METRICS_PREFIX}_exposure_page_count"),
+        "Number of pages the nominator exposure is split into",
+        metrics.exposure_page_count.clone(),
+    );
+    registry.register(
+        format!("{METRICS_PREFIX}_exposure_page_stake"),
+        "Amount staked by nominators in each exposure page",
+        metrics.exposure_page_stake.clone(),
+    );
+    registry.register(
+        format!("{METRICS_PREFIX}_nominators_over_page_size"),
+        "Number of nominators exceeding the maximum exposure page size",
+        metrics.nominators_over_page_size.clone(),
+    );
+    registry.register(
+        format!("{METRICS_PREFIX}_oversubscribed"),
+        "Whether the nominator exposure spans more than one page",
+        metrics.oversubscribed.clone(),
+    );
+    registry.register(
+        format!("{METRICS_PREFIX}_nominations_count"),
+        "Number of nominators targeting the validator, split by whether they back it this era",
+        metrics.nominations_count.clone(),
+    );
+    registry.register(
+        format!("{METRICS_PREFIX}_nominations_stake"),
+        "Bonded stake of nominators targeting the validator, split by whether they back it this era",
+        metrics.nominations_stake.clone(),
+    );
     registry.register(
         format!("{METRICS_PREFIX}_minimum_active_stake"),
         "The minimum active nominator stake of the last successful election",
@@ -58,16 +225,378 @@ async fn main() -> tide::Result<()> {
         "The average amount staked till the current era",
         metrics.average_stake.clone(),
     );
+    registry.register(
+        format!("{METRICS_PREFIX}_active_set_size"),
+        "Number of validators elected into the active set",
+        metrics.active_set_size.clone(),
+    );
+    registry.register(
+        format!("{METRICS_PREFIX}_lowest_elected_backing"),
+        "Total backing of the lowest elected validator",
+        metrics.lowest_elected_backing.clone(),
+    );
+    registry.register(
+        format!("{METRICS_PREFIX}_rank_by_backing"),
+        "Rank of the validator by total backing within the active set (0 if not elected)",
+        metrics.rank_by_backing.clone(),
+    );
+    registry.register(
+        format!("{METRICS_PREFIX}_backing_margin"),
+        "Total backing of the validator above the lowest elected validator",
+        metrics.backing_margin.clone(),
+    );
+    registry.register(
+        format!("{METRICS_PREFIX}_next_era_active"),
+        "Whether the validator is elected for the planned next era",
+        metrics.next_era_active.clone(),
+    );
+    registry.register(
+        format!("{METRICS_PREFIX}_next_era_backing"),
+        "Total backing of the validator in the planned next era",
+        metrics.next_era_backing.clone(),
+    );
+    registry.register(
+        format!("{METRICS_PREFIX}_predicted_elected"),
+        "Whether the validator is elected by a local phragmen run over the election snapshot",
+        metrics.predicted_elected.clone(),
+    );
+    registry.register(
+        format!("{METRICS_PREFIX}_predicted_backing"),
+        "Total backing of the validator predicted from the election snapshot",
+        metrics.predicted_backing.clone(),
+    );
+    registry.register(
+        format!("{METRICS_PREFIX}_election_phase"),
+        "Current phase of the election provider",
+        metrics.election_phase.clone(),
+    );
+    registry.register(
+        format!("{METRICS_PREFIX}_election_emergency"),
+        "Whether the election provider is in emergency phase after a failed election",
+        metrics.election_emergency.clone(),
+    );
+    registry.register(
+        format!("{METRICS_PREFIX}_election_snapshot_voters"),
+        "Number of voters in the election snapshot",
+        metrics.election_snapshot_voters.clone(),
+    );
+    registry.register(
+        format!("{METRICS_PREFIX}_election_snapshot_targets"),
+        "Number of targets in the election snapshot",
+        metrics.election_snapshot_targets.clone(),
+    );
+    registry.register(
+        format!("{METRICS_PREFIX}_queued_solution_minimal_stake"),
+        "Minimal backing of the queued election solution",
+        metrics.queued_solution_minimal_stake.clone(),
+    );
+    registry.register(
+        format!("{METRICS_PREFIX}_queued_solution_sum_stake"),
+        "Total backing of the queued election solution",
+        metrics.queued_solution_sum_stake.clone(),
+    );
+    registry.register(
+        format!("{METRICS_PREFIX}_signed_submissions"),
+        "Number of signed solutions submitted in the ongoing election round",
+        metrics.signed_submissions.clone(),
+    );
+    registry.register(
+        format!("{METRICS_PREFIX}_era_start_timestamp"),
+        "Unix timestamp in seconds at which the active era started",
+        metrics.era_start_timestamp.clone(),
+    );
+    registry.register(
+        format!("{METRICS_PREFIX}_era_elapsed_seconds"),
+        "Seconds elapsed since the active era started",
+        metrics.era_elapsed_seconds.clone(),
+    );
+    registry.register(
+        format!("{METRICS_PREFIX}_era_remaining_seconds"),
+        "Estimated seconds until the next era starts",
+        metrics.era_remaining_seconds.clone(),
+    );
+    registry.register(
+        format!("{METRICS_PREFIX}_era_progress"),
+        "Fraction of the active era that has elapsed",
+        metrics.era_progress.clone(),
+    );
+    registry.register(
+        format!("{METRICS_PREFIX}_session_index"),
+        "Current session index",
+        metrics.session_index.clone(),
+    );
+    registry.register(
+        format!("{METRICS_PREFIX}_session_in_era"),
+        "Index of the current session within the active era",
+        metrics.session_in_era.clone(),
+    );
+    registry.register(
+        format!("{METRICS_PREFIX}_history_eras"),
+        "Number of completed eras covered by the rolling history metrics",
+        metrics.history_eras.clone(),
+    );
+    registry.register(
+        format!("{METRICS_PREFIX}_history_eras_active"),
+        "Number of eras the validator was active in over the rolling history",
+        metrics.history_eras_active.clone(),
+    );
+    registry.register(
+        format!("{METRICS_PREFIX}_history_average_era_points"),
+        "Average era points over the eras the validator was active in over the rolling history",
+        metrics.history_average_era_points.clone(),
+    );
+    registry.register(
+        format!("{METRICS_PREFIX}_history_average_backing"),
+        "Average total backing over the eras the validator was active in over the rolling history",
+        metrics.history_average_backing.clone(),
+    );
+    registry.register(
+        format!("{METRICS_PREFIX}_estimated_payout"),
+        "Estimated validator payout for the last completed era",
+        metrics.estimated_payout.clone(),
+    );
+    registry.register(
+        format!("{METRICS_PREFIX}_commission_payout"),
+        "Commission portion of the estimated payout for the last completed era",
+        metrics.commission_payout.clone(),
+    );
+    registry.register(
+        format!("{METRICS_PREFIX}_nominator_payout"),
+        "Nominator portion of the estimated payout for the last completed era",
+        metrics.nominator_payout.clone(),
+    );
+    registry.register(
+        format!("{METRICS_PREFIX}_apy"),
+        "Annualized return per staked token after commission, based on the last completed era",
+        metrics.apy.clone(),
+    );
+    registry.register(
+        format!("{METRICS_PREFIX}_average_apy"),
+        "Annualized return per staked token after commission across the active set, based on the last completed era",
+        metrics.average_apy.clone(),
+    );
+    registry.register(
+        format!("{METRICS_PREFIX}_unclaimed_eras"),
+        "Number of completed eras with unclaimed validator payouts",
+        metrics.unclaimed_eras.clone(),
+    );
+    registry.register(
+        format!("{METRICS_PREFIX}_oldest_unclaimed_era"),
+        "Oldest era with unclaimed validator payouts",
+        metrics.oldest_unclaimed_era.clone(),
+    );
+    registry.register(
+        format!("{METRICS_PREFIX}_eras_until_expiry"),
+        "Number of era changes until the oldest unclaimed payout expires",
+        metrics.eras_until_expiry.clone(),
+    );
+    registry.register(
+        format!("{METRICS_PREFIX}_unclaimed_pages"),
+        "Number of unclaimed exposure pages in each era with unclaimed payouts",
+        metrics.unclaimed_pages.clone(),
+    );
+    registry.register(
+        format!("{METRICS_PREFIX}_payouts_succeeded"),
+        "Number of exposure pages paid out by the payout bot",
+        metrics.payouts_succeeded.clone(),
+    );
+    registry.register(
+        format!("{METRICS_PREFIX}_payouts_failed"),
+        "Number of exposure pages the payout bot failed to pay out",
+        metrics.payouts_failed.clone(),
+    );
+    registry.register(
+        format!("{METRICS_PREFIX}_payout_fees"),
+        "Transaction fees spent by the payout bot in tokens",
+        metrics.payout_fees.clone(),
+    );
+    registry.register(
+        format!("{METRICS_PREFIX}_payouts_started"),
+        "Number of finalized payouts started for the validator, by era",
+        metrics.payouts_started.clone(),
+    );
+    registry.register(
+        format!("{METRICS_PREFIX}_rewards_received_planck"),
+        "Rewards received by the validator and its nominators in planck, by era",
+        metrics.rewards_received_planck.clone(),
+    );
+    registry.register(
+        format!("{METRICS_PREFIX}_rewards_received_tokens"),
+        "Rewards received by the validator and its nominators in tokens, by era",
+        metrics.rewards_received_tokens.clone(),
+    );
+    registry.register(
+        format!("{METRICS_PREFIX}_pending_slashes"),
+        "Number of unapplied slashes of the validator",
+        metrics.pending_slashes.clone(),
+    );
+    registry.register(
+        format!("{METRICS_PREFIX}_pending_slash_amount"),
+        "Amount to be slashed from the validator and its nominators by unapplied slashes",
+        metrics.pending_slash_amount.clone(),
+    );
+    registry.register(
+        format!("{METRICS_PREFIX}_pending_slash_era"),
+        "Era the earliest unapplied slash of the validator will be applied at",
+        metrics.pending_slash_era.clone(),
+    );
+    registry.register(
+        format!("{METRICS_PREFIX}_slashed_amount"),
+        "Amount slashed from the validator within the history depth",
+        metrics.slashed_amount.clone(),
+    );
+    registry.register(
+        format!("{METRICS_PREFIX}_slashing_span_index"),
+        "Index of the current slashing span of the validator",
+        metrics.slashing_span_index.clone(),
+    );
+    registry.register(
+        format!("{METRICS_PREFIX}_last_slash_era"),
+        "Last era the validator was slashed in",
+        metrics.last_slash_era.clone(),
+    );
+    registry.register(
+        format!("{METRICS_PREFIX}_offences"),
+        "Number of offence reports against the validator, by kind",
+        metrics.offences.clone(),
+    );
+    registry.register(
+        format!("{METRICS_PREFIX}_disabled"),
+        "Whether the validator is disabled for the rest of the era",
+        metrics.disabled.clone(),
+    );
+    registry.register(
+        format!("{METRICS_PREFIX}_disabled_validators"),
+        "Number of validators disabled for the rest of the era",
+        metrics.disabled_validators.clone(),
+    );
+    registry.register(
+        format!("{METRICS_PREFIX}_session_keys_pending"),
+        "Whether the validator set session keys that are not active yet",
+        metrics.session_keys_pending.clone(),
+    );
+    registry.register(
+        format!("{METRICS_PREFIX}_session_keys_activation"),
+        "Session in which the pending session keys of the validator become active",
+        metrics.session_keys_activation.clone(),
+    );
+    registry.register(
+        format!("{METRICS_PREFIX}_session_key"),
+        "Public session keys set by the validator, by key type",
+        metrics.session_key.clone(),
+    );
+    registry.register(
+        format!("{METRICS_PREFIX}_node_rpc_health"),
+        "Whether the RPC of the validator node is healthy",
+        metrics.node_rpc_health.clone(),
+    );
+    registry.register(
+        format!("{METRICS_PREFIX}_node_peers"),
+        "Number of peers connected to the validator node",
+        metrics.node_peers.clone(),
+    );
+    registry.register(
+        format!("{METRICS_PREFIX}_node_syncing"),
+        "Whether the validator node is major syncing",
+        metrics.node_syncing.clone(),
+    );
+    registry.register(
+        format!("{METRICS_PREFIX}_node_best_block"),
+        "Best block number of the validator node",
+        metrics.node_best_block.clone(),
+    );
+    registry.register(
+        format!("{METRICS_PREFIX}_node_block_lag"),
+        "Number of blocks the validator node is behind the staking or relay chain RPC running its chain",
+        metrics.node_block_lag.clone(),
+    );
+    registry.register(
+        format!("{METRICS_PREFIX}_node_version"),
+        "Client version and chain of the validator node",
+        metrics.node_version.clone(),
+    );
+    registry.register(
+        format!("{METRICS_PREFIX}_node_has_session_keys"),
+        "Whether the validator node keystore holds the on-chain session keys",
+        metrics.node_has_session_keys.clone(),
+    );
+    registry.register(
+        format!("{METRICS_PREFIX}_blocks_authored"),
+        "Number of finalized blocks authored by the validator since startup",
+        metrics.blocks_authored.clone(),
+    );
+    registry.register(
+        format!("{METRICS_PREFIX}_blocks_authored_session"),
+        "Number of finalized blocks authored by the validator in the current session",
+        metrics.blocks_authored_session.clone(),
+    );
+    registry.register(
+        format!("{METRICS_PREFIX}_blocks_authored_era"),
+        "Number of finalized blocks authored by the validator in the active era",
+        metrics.blocks_authored_era.clone(),
+    );
+    registry.register(
+        format!("{METRICS_PREFIX}_last_authored_block_timestamp"),
+        "Unix timestamp in seconds of the last finalized block authored by the validator",
+        metrics.last_authored_block_timestamp.clone(),
+    );
+    registry.register(
+        format!("{METRICS_PREFIX}_epoch_secondary_slots"),
+        "Number of secondary slots assigned to the validator in the current epoch",
+        metrics.epoch_secondary_slots.clone(),
+    );
+    registry.register(
+        format!("{METRICS_PREFIX}_secondary_slots"),
+        "Number of assigned secondary slots passed since startup",
+        metrics.secondary_slots.clone(),
+    );
+    registry.register(
+        format!("{METRICS_PREFIX}_secondary_slots_missed"),
+        "Number of assigned secondary slots without a finalized block since startup",
+        metrics.secondary_slots_missed.clone(),
+    );
+    registry.register(
+        format!("{METRICS_PREFIX}_secondary_slot_miss_ratio"),
+        "Share of assigned secondary slots missed since startup",
+        metrics.secondary_slot_miss_ratio.clone(),
+    );
+    registry.register(
+        format!("{METRICS_PREFIX}_grandpa_rounds_missed"),
+        "Number of completed grandpa rounds the validator did not vote in, by vote kind",
+        metrics.grandpa_rounds_missed.clone(),
+    );
+    registry.register(
+        format!("{METRICS_PREFIX}_grandpa_voting"),
+        "Whether the validator has prevoted in the current grandpa round",
+        metrics.grandpa_voting.clone(),
+    );
     registry.register(
         format!("{METRICS_PREFIX}_asset_hub_rpc_health"),
         "Whether at least one RPC endpoint is healthy",
         metrics.asset_hub_rpc_health.clone(),
     );
+    registry.register(
+        format!("{METRICS_PREFIX}_relay_rpc_health"),
+        "Whether the relay chain RPC endpoint is healthy",
+        metrics.relay_rpc_health.clone(),
+    );
+    // open historical store
+    let store = config
+        .store_path
+        .as_ref()
+        .map(|path| Store::open(path).expect("Unable to open historical store!"))
+        .map(Arc::new);
+
     let state = State {
         config: Arc::new(config),
         registry: Arc::new(registry),
         metrics: Arc::new(metrics),
         rpc: Arc::new(RwLock::new(None)),
+        relay_rpc: Arc::new(RwLock::new(None)),
+        nominations: Arc::new(RwLock::new(None)),
+        unclaimed: Arc::new(RwLock::new(Default::default())),
+        history: Arc::new(RwLock::new(Default::default())),
+        store,
         shutdown: Arc::new(RwLock::new(false)),
     };
     task::spawn({
@@ -89,6 +618,12 @@ async fn main() -> tide::Result<()> {
     let mut app = tide::with_state(state.clone());
 
     app.at("/metrics").get(handle_metrics);
+    app.at("/api/validators/:address/eras")
+        .get(handle_validator_eras);
+    app.at("/api/validators/:address/rewards")
+        .get(handle_validator_rewards);
+    app.at("/api/validators/:address/slashing")
+        .get(handle_validator_slashing);
 
     task::spawn({
         let host = args.host.clone();
@@ -103,10 +638,34 @@ async fn main() -> tide::Result<()> {
     });
 
     task::spawn(rpc_manager(state.clone()));
+    task::spawn(relay_rpc_manager(state.clone()));
 
     // WORKERS
     task::spawn(substratheus::workers::chain_metrics_worker(state.clone()));
+    task::spawn(substratheus::workers::nomination_index_worker(
+        state.clone(),
+    ));
+    task::spawn(substratheus::workers::election_margin_worker(state.clone()));
+    task::spawn(substratheus::workers::election_prediction_worker(
+        state.clone(),
+    ));
+    task::spawn(substratheus::workers::history_worker(state.clone()));
+    task::spawn(substratheus::workers::reward_worker(state.clone()));
+    task::spawn(substratheus::workers::unclaimed_payouts_worker(
+        state.clone(),
+    ));
+    task::spawn(substratheus::workers::finalized_block_worker(state.clone()));
+    task::spawn(substratheus::workers::slashing_worker(state.clone()));
+    task::spawn(substratheus::workers::session_worker(state.clone()));
+    task::spawn(substratheus::workers::node_worker(state.clone()));
 
+    if let Some(payouts) = state.config.payouts.as_ref() {
+        let signer = load_signer(&payouts.keystore).expect("Unable to load payout keystore!");
+        task::spawn(substratheus::workers::payout_worker(state.clone(), signer));
+    }
+    if let Some(url) = state.config.grandpa_rpc_url.clone() {
+        task::spawn(substratheus::workers::grandpa_worker(state.clone(), url));
+    }
     for validator in state.config.validators.clone() {
         task::spawn(substratheus::workers::validator_metrics_worker(
             state.clone(),
@@ -0,0 +1,156 @@
+use log::{info, warn};
+use serde::Deserialize;
+use std::collections::BTreeSet;
+use subxt::backend::{
+    legacy::{rpc_methods::SystemHealth, LegacyRpcMethods},
+    rpc::RpcClient,
+};
+use subxt::ext::subxt_rpcs::client::rpc_params;
+use subxt::PolkadotConfig;
+
+type NodeResult<T> = Result<T, Box<dyn std::error::Error + Send + Sync>>;
+
+// block sync progress of a node
+#[derive(Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct SyncState {
+    pub starting_block: u32,
+    pub current_block: u32,
+    pub highest_block: u32,
+}
+
+// votes of a grandpa round, missing voters are listed by authority id
+#[derive(Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct RoundVotes {
+    pub current_weight: u32,
+    pub missing: BTreeSet<String>,
+}
+
+// state of a grandpa round
+#[derive(Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct RoundState {
+    pub round: u64,
+    pub total_weight: u32,
+    pub threshold_weight: u32,
+    pub prevotes: RoundVotes,
+    pub precommits: RoundVotes,
+}
+
+// best and background grandpa rounds of the current authority set
+#[derive(Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ReportedRoundStates {
+    pub set_id: u64,
+    pub best: RoundState,
+    pub background: Vec<RoundState>,
+}
+
+// rpc client of a validator's own node
+pub struct NodeRPC {
+    url: String,
+    client: RpcClient,
+    rpc: LegacyRpcMethods<PolkadotConfig>,
+}
+
+impl NodeRPC {
+    pub async fn new(url: &str) -> NodeResult<Self> {
+        let client = RpcClient::from_url(url).await?;
+        Ok(Self {
+            url: url.to_string(),
+            client: client.clone(),
+            rpc: LegacyRpcMethods::new(client),
+        })
+    }
+
+    // get the peer count and sync status of the node
+    pub async fn get_health(&self) -> Option<SystemHealth> {
+        match self.rpc.system_health().await {
+            Ok(health) => {
+                info!("Fetched health of node {}: {health:?}", self.url);
+                Some(health)
+            }
+            Err(e) => {
+                warn!("Unable to fetch health of node {}: {e}", self.url);
+                None
+            }
+        }
+    }
+
+    // get the block sync progress of the node
+    pub async fn get_sync_state(&self) -> Option<SyncState> {
+        match self
+            .client
+            .request::<SyncState>("system_syncState", rpc_params![])
+            .await
+        {
+            Ok(sync_state) => {
+                info!("Fetched sync state of node {}: {sync_state:?}", self.url);
+                Some(sync_state)
+            }
+            Err(e) => {
+                warn!("Unable to fetch sync state of node {}: {e}", self.url);
+                None
+            }
+        }
+    }
+
+    // get the grandpa rounds the node is voting in
+    pub async fn get_round_state(&self) -> Option<ReportedRoundStates> {
+        match self
+            .client
+            .request::<ReportedRoundStates>("grandpa_roundState", rpc_params![])
+            .await
+        {
+            Ok(round_state) => {
+                info!(
+                    "Fetched grandpa round {} of set {} from node {}",
+                    round_state.best.round, round_state.set_id, self.url
+                );
+                Some(round_state)
+            }
+            Err(e) => {
+                warn!(
+                    "Unable to fetch grandpa round state of node {}: {e}",
+                    self.url
+                );
+                None
+            }
+        }
+    }
+
+    // get the client version of the node
+    pub async fn get_version(&self) -> Option<String> {
+        match self.rpc.system_version().await {
+            Ok(version) => Some(version),
+            Err(e) => {
+                warn!("Unable to fetch version of node {}: {e}", self.url);
+                None
+            }
+        }
+    }
+
+    // get the chain name the node is running
+    pub async fn get_chain(&self) -> Option<String> {
+        match self.rpc.system_chain().await {
+            Ok(chain) => Some(chain),
+            Err(e) => {
+                warn!("Unable to fetch chain of node {}: {e}", self.url);
+                None
+            }
+        }
+    }
+
+    // check whether the node keystore holds the private keys of the given encoded session keys,
+    // only exposed by nodes running with unsafe rpc methods
+    pub async fn has_session_keys(&self, session_keys: Vec<u8>) -> Option<bool> {
+        match self.rpc.author_has_session_keys(session_keys).await {
+            Ok(has_keys) => Some(has_keys),
+            Err(e) => {
+                warn!("Unable to check session keys of node {}: {e}", self.url);
+                None
+            }
+        }
+    }
+}
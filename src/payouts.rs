@@ -0,0 +1,301 @@
+use crate::substrate::{Era, PayoutReceipt, SubstrateRPC, Tokens, UnclaimedPayouts};
+
+use log::{info, warn};
+use std::collections::BTreeSet;
+use std::fs;
+use std::future::Future;
+use std::sync::Arc;
+use subxt::utils::AccountId32;
+use subxt_signer::{sr25519::Keypair, SecretUri};
+
+type PayoutResult<T> = Result<T, Box<dyn std::error::Error + Send + Sync>>;
+
+// a single exposure page of a validator to pay out, ordered oldest era first
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct PayoutCall {
+    pub era: Era,
+    pub validator: String,
+    pub page: u32,
+}
+
+// chain accepting batches of payout calls
+pub trait PayoutChain {
+    // submit the calls atomically, resolving once the batch is finalized
+    fn submit(
+        &self,
+        calls: &[PayoutCall],
+    ) -> impl Future<Output = PayoutResult<PayoutReceipt>> + Send;
+}
+
+// payout account submitting through the exporter's rpc client
+pub struct Payer {
+    rpc: Arc<SubstrateRPC>,
+    signer: Keypair,
+}
+impl Payer {
+    pub fn new(rpc: Arc<SubstrateRPC>, signer: Keypair) -> Self {
+        Self { rpc, signer }
+    }
+}
+impl PayoutChain for Payer {
+    async fn submit(&self, calls: &[PayoutCall]) -> PayoutResult<PayoutReceipt> {
+        let mut payouts = vec![];
+        for call in calls {
+            payouts.push((call.validator.parse::<AccountId32>()?, call.era, call.page));
+        }
+        self.rpc.submit_payouts(&self.signer, &payouts).await
+    }
+}
+
+// load the payout account from a keystore file holding a secret phrase or uri,
+// either as plain text or as a json string like the node keystore
+pub fn load_signer(path: &str) -> PayoutResult<Keypair> {
+    let contents = fs::read_to_string(path)?;
+    let contents = contents.trim();
+    let secret = match contents.starts_with('"') {
+        true => serde_json::from_str::<String>(contents)?,
+        false => contents.to_string(),
+    };
+    let uri: SecretUri = secret.parse()?;
+    Ok(Keypair::from_uri(&uri)?)
+}
+
+// unclaimed pages not attempted yet, oldest era first
+pub fn pending_payouts(
+    unclaimed: &UnclaimedPayouts,
+    attempted: &BTreeSet<PayoutCall>,
+) -> Vec<PayoutCall> {
+    let mut calls: Vec<PayoutCall> = unclaimed
+        .iter()
+        .flat_map(|(validator, eras)| {
+            eras.iter().flat_map(move |(era, pages)| {
+                pages.iter().map(move |page| PayoutCall {
+                    era: *era,
+                    validator: validator.clone(),
+                    page: *page,
+                })
+            })
+        })
+        .filter(|call| !attempted.contains(call))
+        .collect();
+    calls.sort();
+    calls
+}
+
+// outcome of a payout run
+#[derive(Debug, Default)]
+pub struct PayoutReport {
+    pub paid: Vec<PayoutCall>,
+    pub failed: Vec<PayoutCall>,
+    pub fees: Tokens,
+}
+
+// pay out the given calls in batches, only logging them in dry-run mode
+pub async fn pay_out<C: PayoutChain>(
+    chain: &C,
+    calls: &[PayoutCall],
+    batch_size: usize,
+    dry_run: bool,
+) -> PayoutReport {
+    let mut report = PayoutReport::default();
+
+    for batch in calls.chunks(batch_size.max(1)) {
+        if dry_run {
+            for call in batch {
+                info!(
+                    "Dry run, skipping payout of page {} at era {} for {}",
+                    call.page, call.era, call.validator
+                );
+            }
+            continue;
+        }
+
+        match chain.submit(batch).await {
+            Ok(receipt) => {
+                report.fees += receipt.fee;
+                if receipt.success {
+                    report.paid.extend_from_slice(batch);
+                } else {
+                    warn!(
+                        "Payout batch of {} pages failed in block {:?}!",
+                        batch.len(),
+                        receipt.block_hash
+                    );
+                    report.failed.extend_from_slice(batch);
+                }
+            }
+            Err(e) => {
+                warn!(
+                    "Unable to submit payout batch of {} pages: {e}",
+                    batch.len()
+                );
+                report.failed.extend_from_slice(batch);
+            }
+        }
+    }
+
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_std::task;
+    use std::collections::BTreeMap;
+    use std::sync::Mutex;
+
+    const FEE: Tokens = 1_000;
+
+    // in-memory dev chain enforcing the payout_stakers_by_page and batch_all rules
+    #[derive(Default)]
+    struct DevChain {
+        // exposure page count per validator and era
+        pages: BTreeMap<(String, Era), u32>,
+        claimed: Mutex<BTreeSet<PayoutCall>>,
+        batches: Mutex<Vec<usize>>,
+    }
+    impl DevChain {
+        fn new(pages: &[(&str, Era, u32)]) -> Self {
+            Self {
+                pages: pages
+                    .iter()
+                    .map(|(validator, era, count)| ((validator.to_string(), *era), *count))
+                    .collect(),
+                ..Default::default()
+            }
+        }
+
+        fn unclaimed(&self) -> UnclaimedPayouts {
+            let claimed = self.claimed.lock().unwrap();
+            let mut unclaimed = UnclaimedPayouts::new();
+            for ((validator, era), count) in self.pages.iter() {
+                let pages: Vec<u32> = (0..*count)
+                    .filter(|page| {
+                        !claimed.contains(&PayoutCall {
+                            era: *era,
+                            validator: validator.clone(),
+                            page: *page,
+                        })
+                    })
+                    .collect();
+                if !pages.is_empty() {
+                    unclaimed
+                        .entry(validator.clone())
+                        .or_default()
+                        .insert(*era, pages);
+                }
+            }
+            unclaimed
+        }
+    }
+    impl PayoutChain for DevChain {
+        async fn submit(&self, calls: &[PayoutCall]) -> PayoutResult<PayoutReceipt> {
+            self.batches.lock().unwrap().push(calls.len());
+            let mut claimed = self.claimed.lock().unwrap();
+
+            // batch_all reverts every call if one of them fails
+            let mut batch = BTreeSet::new();
+            let valid = calls.iter().all(|call| {
+                let count = self
+                    .pages
+                    .get(&(call.validator.clone(), call.era))
+                    .copied()
+                    .unwrap_or_default();
+                call.page < count && !claimed.contains(call) && batch.insert(call.clone())
+            });
+            if valid {
+                claimed.extend(batch);
+            }
+
+            Ok(PayoutReceipt {
+                success: valid,
+                fee: FEE,
+                ..Default::default()
+            })
+        }
+    }
+
+    fn call(validator: &str, era: Era, page: u32) -> PayoutCall {
+        PayoutCall {
+            era,
+            validator: validator.into(),
+            page,
+        }
+    }
+
+    #[test]
+    fn pending_payouts_are_ordered_oldest_era_first() {
+        let chain = DevChain::new(&[("alice", 12, 1), ("bob", 10, 2), ("alice", 11, 1)]);
+        let attempted = BTreeSet::from([call("bob", 10, 1)]);
+
+        let calls = pending_payouts(&chain.unclaimed(), &attempted);
+        assert_eq!(
+            calls,
+            vec![
+                call("bob", 10, 0),
+                call("alice", 11, 0),
+                call("alice", 12, 0)
+            ]
+        );
+    }
+
+    #[test]
+    fn pays_out_all_pages_in_batches() {
+        let chain = DevChain::new(&[("alice", 10, 3), ("bob", 10, 1), ("bob", 11, 1)]);
+        let calls = pending_payouts(&chain.unclaimed(), &BTreeSet::new());
+
+        let report = task::block_on(pay_out(&chain, &calls, 2, false));
+        assert_eq!(report.paid, calls);
+        assert!(report.failed.is_empty());
+        assert_eq!(report.fees, 3 * FEE);
+        assert_eq!(*chain.batches.lock().unwrap(), vec![2, 2, 1]);
+        assert!(chain.unclaimed().is_empty());
+    }
+
+    #[test]
+    fn dry_run_submits_nothing() {
+        let chain = DevChain::new(&[("alice", 10, 2)]);
+        let calls = pending_payouts(&chain.unclaimed(), &BTreeSet::new());
+
+        let report = task::block_on(pay_out(&chain, &calls, 8, true));
+        assert!(report.paid.is_empty());
+        assert!(report.failed.is_empty());
+        assert_eq!(report.fees, 0);
+        assert!(chain.batches.lock().unwrap().is_empty());
+        assert_eq!(chain.unclaimed().len(), 1);
+    }
+
+    #[test]
+    fn failed_batch_reverts_and_still_pays_fee() {
+        let chain = DevChain::new(&[("alice", 10, 2)]);
+        chain.claimed.lock().unwrap().insert(call("alice", 10, 1));
+        let calls = vec![call("alice", 10, 0), call("alice", 10, 1)];
+
+        let report = task::block_on(pay_out(&chain, &calls, 8, false));
+        assert!(report.paid.is_empty());
+        assert_eq!(report.failed, calls);
+        assert_eq!(report.fees, FEE);
+        assert_eq!(
+            chain
+                .unclaimed()
+                .get("alice")
+                .and_then(|eras| eras.get(&10)),
+            Some(&vec![0])
+        );
+    }
+
+    #[test]
+    fn loads_signer_from_keystore_file() {
+        let dir = std::env::temp_dir().join(format!("substratheus-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let alice = subxt_signer::sr25519::dev::alice().public_key().0;
+
+        for (name, contents) in [("plain", "//Alice\n"), ("json", "\"//Alice\"")] {
+            let path = dir.join(name);
+            fs::write(&path, contents).unwrap();
+            let signer = load_signer(path.to_str().unwrap()).unwrap();
+            assert_eq!(signer.public_key().0, alice);
+        }
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,289 @@
+use std::collections::BTreeMap;
+use subxt::utils::AccountId32;
+
+// number of balancing rounds run after the sequential phragmen election
+const BALANCING_ITERATIONS: usize = 10;
+
+// election snapshot voter: account, vote weight and nominated targets
+pub type Voter = (AccountId32, u64, Vec<AccountId32>);
+
+struct Edge {
+    candidate: usize,
+    load: f64,
+    weight: f64,
+}
+
+struct PhragmenVoter {
+    budget: f64,
+    load: f64,
+    edges: Vec<Edge>,
+}
+
+struct Candidate {
+    approval: f64,
+    score: f64,
+    elected: bool,
+    backing: f64,
+}
+
+// elect `to_elect` targets with sequential phragmen followed by balancing,
+// returning the elected targets with their total backing in vote weight
+pub fn elect(
+    voters: &[Voter],
+    targets: &[AccountId32],
+    to_elect: usize,
+) -> Vec<(AccountId32, u128)> {
+    let index: BTreeMap<&AccountId32, usize> =
+        targets.iter().enumerate().map(|(i, t)| (t, i)).collect();
+
+    let mut candidates: Vec<Candidate> = targets
+        .iter()
+        .map(|_| Candidate {
+            approval: 0.0,
+            score: 0.0,
+            elected: false,
+            backing: 0.0,
+        })
+        .collect();
+
+    let mut voters: Vec<PhragmenVoter> = voters
+        .iter()
+        .map(|(_, stake, votes)| {
+            let mut edges: Vec<Edge> = votes
+                .iter()
+                .filter_map(|t| index.get(t))
+                .map(|&candidate| Edge {
+                    candidate,
+                    load: 0.0,
+                    weight: 0.0,
+                })
+                .collect();
+            edges.sort_by_key(|e| e.candidate);
+            edges.dedup_by_key(|e| e.candidate);
+            PhragmenVoter {
+                budget: *stake as f64,
+                load: 0.0,
+                edges,
+            }
+        })
+        .collect();
+
+    for voter in voters.iter() {
+        for edge in voter.edges.iter() {
+            candidates[edge.candidate].approval += voter.budget;
+        }
+    }
+
+    // sequential phragmen
+    let mut elected = vec![];
+    for _ in 0..to_elect.min(targets.len()) {
+        for c in candidates
+            .iter_mut()
+            .filter(|c| !c.elected && c.approval > 0.0)
+        {
+            c.score = 1.0 / c.approval;
+        }
+        for voter in voters.iter() {
+            for edge in voter.edges.iter() {
+                let c = &mut candidates[edge.candidate];
+                if !c.elected && c.approval > 0.0 {
+                    c.score += voter.budget * voter.load / c.approval;
+                }
+            }
+        }
+
+        let winner = candidates
+            .iter()
+            .enumerate()
+            .filter(|(_, c)| !c.elected && c.approval > 0.0)
+            .min_by(|(_, a), (_, b)| a.score.total_cmp(&b.score))
+            .map(|(i, _)| i);
+        let Some(winner) = winner else {
+            break;
+        };
+
+        let score = candidates[winner].score;
+        candidates[winner].elected = true;
+        elected.push(winner);
+
+        for voter in voters.iter_mut() {
+            if let Some(edge) = voter.edges.iter_mut().find(|e| e.candidate == winner) {
+                edge.load = score - voter.load;
+                voter.load = score;
+            }
+        }
+    }
+
+    // distribute each voter's budget over the elected targets
+    for voter in voters.iter_mut() {
+        voter.edges.retain(|e| candidates[e.candidate].elected);
+        for edge in voter.edges.iter_mut() {
+            if voter.load > 0.0 {
+                edge.weight = voter.budget * edge.load / voter.load;
+            }
+            candidates[edge.candidate].backing += edge.weight;
+        }
+    }
+
+    // balancing
+    for _ in 0..BALANCING_ITERATIONS {
+        for voter in voters.iter_mut() {
+            balance_voter(voter, &mut candidates);
+        }
+    }
+
+    elected
+        .into_iter()
+        .map(|i| (targets[i].clone(), candidates[i].backing as u128))
+        .collect()
+}
+
+// spread a voter's budget so that the targets it backs end up as even as possible
+fn balance_voter(voter: &mut PhragmenVoter, candidates: &mut [Candidate]) {
+    if voter.edges.len() <= 1 {
+        return;
+    }
+
+    for edge in voter.edges.iter_mut() {
+        candidates[edge.candidate].backing -= edge.weight;
+        edge.weight = 0.0;
+    }
+
+    voter.edges.sort_by(|a, b| {
+        candidates[a.candidate]
+            .backing
+            .total_cmp(&candidates[b.candidate].backing)
+    });
+
+    let mut cumulative = 0.0;
+    let mut last_index = voter.edges.len() - 1;
+    for (i, edge) in voter.edges.iter().enumerate() {
+        let backing = candidates[edge.candidate].backing;
+        if backing * i as f64 - cumulative > voter.budget {
+            last_index = i - 1;
+            break;
+        }
+        cumulative += backing;
+    }
+
+    let last_backing = candidates[voter.edges[last_index].candidate].backing;
+    let ways_to_split = (last_index + 1) as f64;
+    let excess = voter.budget + cumulative - last_backing * ways_to_split;
+
+    for edge in voter.edges[..=last_index].iter_mut() {
+        let candidate = &mut candidates[edge.candidate];
+        edge.weight = excess / ways_to_split + last_backing - candidate.backing;
+        candidate.backing += edge.weight;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn account(id: u8) -> AccountId32 {
+        AccountId32([id; 32])
+    }
+
+    fn candidate(backing: f64) -> Candidate {
+        Candidate {
+            approval: 0.0,
+            score: 0.0,
+            elected: true,
+            backing,
+        }
+    }
+
+    fn edge(candidate: usize, weight: f64) -> Edge {
+        Edge {
+            candidate,
+            load: 0.0,
+            weight,
+        }
+    }
+
+    fn weight(voter: &PhragmenVoter, candidate: usize) -> f64 {
+        voter
+            .edges
+            .iter()
+            .find(|e| e.candidate == candidate)
+            .map_or(0.0, |e| e.weight)
+    }
+
+    #[test]
+    fn elects_and_balances_small_election() {
+        let (a, b, c) = (account(1), account(2), account(3));
+        let voters = vec![
+            (account(11), 10, vec![a.clone()]),
+            (account(12), 20, vec![a.clone(), b.clone()]),
+            (account(13), 30, vec![b.clone(), c.clone()]),
+        ];
+
+        // approvals are a 30, b 50, c 30: b wins the first round with score 1/50,
+        // a beats c in the second with 1/30 + 20 * 0.02 / 30 against 1/30 + 30 * 0.02 / 30,
+        // balancing then moves the 20 of the second voter fully onto a
+        let elected = elect(&voters, &[a.clone(), b.clone(), c.clone()], 2);
+        let ids: Vec<&AccountId32> = elected.iter().map(|(id, _)| id).collect();
+        assert_eq!(ids, vec![&b, &a]);
+        for (_, backing) in elected.iter() {
+            assert!(backing.abs_diff(30) <= 1, "unexpected backing {backing}");
+        }
+    }
+
+    #[test]
+    fn elects_no_more_than_the_approved_targets() {
+        let (a, b) = (account(1), account(2));
+        let voters = vec![(account(11), 10, vec![a.clone()])];
+
+        let elected = elect(&voters, &[a.clone(), b], 2);
+        assert_eq!(elected, vec![(a, 10)]);
+    }
+
+    #[test]
+    fn balancing_keeps_single_edge() {
+        let mut candidates = vec![candidate(5.0)];
+        let mut voter = PhragmenVoter {
+            budget: 5.0,
+            load: 0.0,
+            edges: vec![edge(0, 5.0)],
+        };
+
+        balance_voter(&mut voter, &mut candidates);
+        assert_eq!(weight(&voter, 0), 5.0);
+        assert_eq!(candidates[0].backing, 5.0);
+    }
+
+    #[test]
+    fn balancing_spends_budget_below_the_gap_on_the_lowest_target() {
+        // without this voter the targets are backed 100 and 0, a gap larger than its budget
+        let mut candidates = vec![candidate(100.0), candidate(10.0)];
+        let mut voter = PhragmenVoter {
+            budget: 10.0,
+            load: 0.0,
+            edges: vec![edge(0, 0.0), edge(1, 10.0)],
+        };
+
+        balance_voter(&mut voter, &mut candidates);
+        assert_eq!(weight(&voter, 0), 0.0);
+        assert_eq!(weight(&voter, 1), 10.0);
+        assert_eq!(candidates[0].backing, 100.0);
+        assert_eq!(candidates[1].backing, 10.0);
+    }
+
+    #[test]
+    fn balancing_evens_out_targets_within_budget() {
+        // without this voter the targets are backed 10 and 0
+        let mut candidates = vec![candidate(40.0), candidate(0.0)];
+        let mut voter = PhragmenVoter {
+            budget: 30.0,
+            load: 0.0,
+            edges: vec![edge(0, 30.0), edge(1, 0.0)],
+        };
+
+        balance_voter(&mut voter, &mut candidates);
+        assert_eq!(weight(&voter, 0), 10.0);
+        assert_eq!(weight(&voter, 1), 20.0);
+        assert_eq!(candidates[0].backing, 20.0);
+        assert_eq!(candidates[1].backing, 20.0);
+    }
+}
@@ -1,6 +1,8 @@
 use prometheus_client::encoding::EncodeLabelSet;
+use prometheus_client::metrics::counter::Counter;
 use prometheus_client::metrics::family::Family;
 use prometheus_client::metrics::gauge::Gauge;
+use std::sync::atomic::AtomicU64;
 
 // prometheus metrics label format
 #[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelSet)]
@@ -11,15 +13,178 @@ pub struct Labels {
     pub validator_address: Option<String>,
 }
 
+// exposure page label format
+#[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelSet)]
+pub struct PageLabels {
+    pub page: u32,
+    #[prometheus(flatten)]
+    pub labels: Labels,
+}
+
+// nomination backing label format
+#[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelSet)]
+pub struct BackingLabels {
+    pub backing: String,
+    #[prometheus(flatten)]
+    pub labels: Labels,
+}
+
+// era label format
+#[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelSet)]
+pub struct EraLabels {
+    pub era: u32,
+    #[prometheus(flatten)]
+    pub labels: Labels,
+}
+
+// reward recipient label format
+#[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelSet)]
+pub struct RewardLabels {
+    pub era: u32,
+    pub recipient: String,
+    #[prometheus(flatten)]
+    pub labels: Labels,
+}
+
+// offence kind label format
+#[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelSet)]
+pub struct KindLabels {
+    pub kind: String,
+    #[prometheus(flatten)]
+    pub labels: Labels,
+}
+
+// session key label format
+#[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelSet)]
+pub struct KeyLabels {
+    pub key_type: String,
+    pub public_key: String,
+    #[prometheus(flatten)]
+    pub labels: Labels,
+}
+
+// node version label format
+#[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelSet)]
+pub struct VersionLabels {
+    pub version: String,
+    pub chain: String,
+    #[prometheus(flatten)]
+    pub labels: Labels,
+}
+
+// election phase label format
+#[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelSet)]
+pub struct PhaseLabels {
+    pub phase: String,
+    #[prometheus(flatten)]
+    pub labels: Labels,
+}
+
 // prometheus metrics
 #[derive(Clone, Default)]
 pub struct Metrics {
     pub era: Family<Labels, Gauge>,
+    pub planned_era: Family<Labels, Gauge>,
     pub active: Family<Labels, Gauge>,
     pub era_points: Family<Labels, Gauge>,
+    pub active_last_era: Family<Labels, Gauge>,
+    pub era_points_last_era: Family<Labels, Gauge>,
+    pub era_points_percentile: Family<Labels, Gauge<f64, AtomicU64>>,
+    pub era_points_median_ratio: Family<Labels, Gauge<f64, AtomicU64>>,
+    pub era_points_mean_ratio: Family<Labels, Gauge<f64, AtomicU64>>,
+    pub era_points_zscore: Family<Labels, Gauge<f64, AtomicU64>>,
+    pub era_points_min: Family<Labels, Gauge<f64, AtomicU64>>,
+    pub era_points_p10: Family<Labels, Gauge<f64, AtomicU64>>,
+    pub era_points_median: Family<Labels, Gauge<f64, AtomicU64>>,
+    pub era_points_p90: Family<Labels, Gauge<f64, AtomicU64>>,
+    pub era_points_max: Family<Labels, Gauge<f64, AtomicU64>>,
+    pub era_points_per_hour: Family<Labels, Gauge<f64, AtomicU64>>,
+    pub era_points_per_block: Family<Labels, Gauge<f64, AtomicU64>>,
+    pub session_era_points: Family<Labels, Gauge>,
+    pub projected_era_points: Family<Labels, Gauge<f64, AtomicU64>>,
+    pub median_era_points_per_hour: Family<Labels, Gauge<f64, AtomicU64>>,
+    pub projected_median_era_points: Family<Labels, Gauge<f64, AtomicU64>>,
+    pub nominator_stake_last_era: Family<Labels, Gauge>,
     pub nominator_stake: Family<Labels, Gauge>,
     pub nominator_count: Family<Labels, Gauge>,
+    pub own_stake: Family<Labels, Gauge>,
+    pub exposure_page_count: Family<Labels, Gauge>,
+    pub exposure_page_stake: Family<PageLabels, Gauge>,
+    pub nominators_over_page_size: Family<Labels, Gauge>,
+    pub oversubscribed: Family<Labels, Gauge>,
+    pub nominations_count: Family<BackingLabels, Gauge>,
+    pub nominations_stake: Family<BackingLabels, Gauge>,
     pub minimum_active_stake: Family<Labels, Gauge>,
     pub average_stake: Family<Labels, Gauge>,
+    pub active_set_size: Family<Labels, Gauge>,
+    pub lowest_elected_backing: Family<Labels, Gauge>,
+    pub rank_by_backing: Family<Labels, Gauge>,
+    pub backing_margin: Family<Labels, Gauge>,
+    pub next_era_active: Family<Labels, Gauge>,
+    pub next_era_backing: Family<Labels, Gauge>,
+    pub predicted_elected: Family<Labels, Gauge>,
+    pub predicted_backing: Family<Labels, Gauge>,
+    pub election_phase: Family<PhaseLabels, Gauge>,
+    pub election_emergency: Family<Labels, Gauge>,
+    pub election_snapshot_voters: Family<Labels, Gauge>,
+    pub election_snapshot_targets: Family<Labels, Gauge>,
+    pub queued_solution_minimal_stake: Family<Labels, Gauge>,
+    pub queued_solution_sum_stake: Family<Labels, Gauge>,
+    pub signed_submissions: Family<Labels, Gauge>,
+    pub era_start_timestamp: Family<Labels, Gauge>,
+    pub era_elapsed_seconds: Family<Labels, Gauge>,
+    pub era_remaining_seconds: Family<Labels, Gauge>,
+    pub era_progress: Family<Labels, Gauge<f64, AtomicU64>>,
+    pub session_index: Family<Labels, Gauge>,
+    pub session_in_era: Family<Labels, Gauge>,
+    pub history_eras: Family<Labels, Gauge>,
+    pub history_eras_active: Family<Labels, Gauge>,
+    pub history_average_era_points: Family<Labels, Gauge<f64, AtomicU64>>,
+    pub history_average_backing: Family<Labels, Gauge>,
+    pub estimated_payout: Family<Labels, Gauge>,
+    pub commission_payout: Family<Labels, Gauge>,
+    pub nominator_payout: Family<Labels, Gauge>,
+    pub apy: Family<Labels, Gauge<f64, AtomicU64>>,
+    pub average_apy: Family<Labels, Gauge<f64, AtomicU64>>,
+    pub unclaimed_eras: Family<Labels, Gauge>,
+    pub oldest_unclaimed_era: Family<Labels, Gauge>,
+    pub eras_until_expiry: Family<Labels, Gauge>,
+    pub unclaimed_pages: Family<EraLabels, Gauge>,
+    pub payouts_succeeded: Family<Labels, Counter>,
+    pub payouts_failed: Family<Labels, Counter>,
+    pub payout_fees: Family<Labels, Counter<f64, AtomicU64>>,
+    pub payouts_started: Family<EraLabels, Counter>,
+    pub rewards_received_planck: Family<RewardLabels, Counter<f64, AtomicU64>>,
+    pub rewards_received_tokens: Family<RewardLabels, Counter<f64, AtomicU64>>,
+    pub pending_slashes: Family<Labels, Gauge>,
+    pub pending_slash_amount: Family<Labels, Gauge>,
+    pub pending_slash_era: Family<Labels, Gauge>,
+    pub slashed_amount: Family<Labels, Gauge>,
+    pub slashing_span_index: Family<Labels, Gauge>,
+    pub last_slash_era: Family<Labels, Gauge>,
+    pub offences: Family<KindLabels, Gauge>,
+    pub disabled: Family<Labels, Gauge>,
+    pub disabled_validators: Family<Labels, Gauge>,
+    pub session_keys_pending: Family<Labels, Gauge>,
+    pub session_keys_activation: Family<Labels, Gauge>,
+    pub session_key: Family<KeyLabels, Gauge>,
+    pub node_rpc_health: Family<Labels, Gauge>,
+    pub node_peers: Family<Labels, Gauge>,
+    pub node_syncing: Family<Labels, Gauge>,
+    pub node_best_block: Family<Labels, Gauge>,
+    pub node_block_lag: Family<Labels, Gauge>,
+    pub node_version: Family<VersionLabels, Gauge>,
+    pub node_has_session_keys: Family<Labels, Gauge>,
+    pub blocks_authored: Family<Labels, Counter>,
+    pub blocks_authored_session: Family<Labels, Gauge>,
+    pub blocks_authored_era: Family<Labels, Gauge>,
+    pub last_authored_block_timestamp: Family<Labels, Gauge>,
+    pub epoch_secondary_slots: Family<Labels, Gauge>,
+    pub secondary_slots: Family<Labels, Counter>,
+    pub secondary_slots_missed: Family<Labels, Counter>,
+    pub secondary_slot_miss_ratio: Family<Labels, Gauge<f64, AtomicU64>>,
+    pub grandpa_rounds_missed: Family<KindLabels, Counter>,
+    pub grandpa_voting: Family<Labels, Gauge>,
     pub asset_hub_rpc_health: Family<Labels, Gauge>,
+    pub relay_rpc_health: Family<Labels, Gauge>,
 }
@@ -0,0 +1,126 @@
+use crate::history::{self, EraRecord};
+use crate::substrate::{Era, SubstrateRPC};
+use crate::utils::{format_tokens, Config};
+
+use log::info;
+use std::fs;
+use std::path::Path;
+
+type ReportResult<T> = Result<T, Box<dyn std::error::Error + Send + Sync>>;
+
+const COLUMNS: [&str; 10] = [
+    "Era",
+    "Validator",
+    "Address",
+    "Active",
+    "Points",
+    "Rank",
+    "Nominators",
+    "Stake",
+    "Commission",
+    "Estimated reward",
+];
+
+// report rows, one per tracked validator and era
+fn rows(config: &Config, records: &[EraRecord]) -> Vec<[String; 10]> {
+    let mut rows = vec![];
+    for validator in config.validators.iter() {
+        for era in records.iter() {
+            let Some(record) = era.validators.get(&validator.address) else {
+                continue;
+            };
+            rows.push([
+                era.era.to_string(),
+                validator.name.clone(),
+                validator.address.clone(),
+                if record.active { "yes" } else { "no" }.into(),
+                record.points.to_string(),
+                match record.rank {
+                    0 => "-".into(),
+                    rank => rank.to_string(),
+                },
+                record.nominator_count.to_string(),
+                format_tokens(&config.network, record.backing),
+                // commission is stored in parts per billion
+                format!("{:.2}%", record.commission as f64 / 10_000_000.0),
+                format_tokens(&config.network, record.reward(era)),
+            ]);
+        }
+    }
+    rows
+}
+
+fn encode_csv(rows: &[[String; 10]]) -> String {
+    let escape = |v: &str| {
+        if v.contains([',', '"', '\n']) {
+            format!("\"{}\"", v.replace('"', "\"\""))
+        } else {
+            v.to_string()
+        }
+    };
+
+    let mut out = COLUMNS.join(",") + "\n";
+    for row in rows {
+        out += &row.iter().map(|v| escape(v)).collect::<Vec<_>>().join(",");
+        out += "\n";
+    }
+    out
+}
+
+fn encode_markdown(title: &str, rows: &[[String; 10]]) -> String {
+    let escape = |v: &str| v.replace('|', "\\|").replace('\n', " ");
+
+    let mut out = format!("# {title}\n\n");
+    out += &format!("| {} |\n", COLUMNS.join(" | "));
+    out += &format!("|{}\n", "---|".repeat(COLUMNS.len()));
+    for row in rows {
+        let cells: Vec<_> = row.iter().map(|v| escape(v)).collect();
+        out += &format!("| {} |\n", cells.join(" | "));
+    }
+    out
+}
+
+// write the results of the given eras as <name>.csv and <name>.md in the given directory
+pub fn write_report(
+    config: &Config,
+    dir: &str,
+    name: &str,
+    records: &[EraRecord],
+) -> ReportResult<()> {
+    let (Some(first), Some(last)) = (records.first(), records.last()) else {
+        return Ok(());
+    };
+    let title = match first.era == last.era {
+        true => format!("{} validator report for era {}", config.chain, first.era),
+        false => format!(
+            "{} validator report for eras {} to {}",
+            config.chain, first.era, last.era
+        ),
+    };
+    let rows = rows(config, records);
+
+    let dir = Path::new(dir);
+    fs::create_dir_all(dir)?;
+    fs::write(dir.join(format!("{name}.csv")), encode_csv(&rows))?;
+    fs::write(
+        dir.join(format!("{name}.md")),
+        encode_markdown(&title, &rows),
+    )?;
+    info!("Wrote report {name} to {}", dir.display());
+    Ok(())
+}
+
+// write a report of the given (inclusive) era range
+pub async fn report(config: &Config, from_era: Era, to_era: Era, dir: &str) -> ReportResult<()> {
+    let rpc = SubstrateRPC::new(config.network.clone(), &config.rpc_url).await?;
+
+    let mut records = vec![];
+    for era in from_era..=to_era {
+        let record = history::fetch_era_record(&rpc, era, &config.validators)
+            .await
+            .ok_or(format!("Unable to fetch results of era {era}"))?;
+        records.push(record);
+    }
+
+    write_report(config, dir, &format!("eras-{from_era}-{to_era}"), &records)
+}
@@ -0,0 +1,97 @@
+use crate::substrate::{EraPoints, EraPointsMap, NominatorSummary, Tokens};
+
+use std::collections::BTreeMap;
+use subxt::utils::AccountId32;
+
+// milliseconds in a julian year
+const YEAR: f64 = 31_557_600_000.0;
+
+// estimated payout of a validator in a completed era
+#[derive(Debug, Clone, Default)]
+pub struct PayoutEstimate {
+    pub payout: Tokens,
+    pub commission: Tokens,
+    pub nominators: Tokens,
+    // return per staked token after commission
+    pub rate: f64,
+    // total backing the payout after commission is shared over
+    pub backing: Tokens,
+}
+
+impl PayoutEstimate {
+    // payout after commission, shared by the validator and nominators pro rata
+    pub fn shared(&self) -> Tokens {
+        self.payout - self.commission
+    }
+}
+
+// share of an era's validator reward earned through era points
+pub fn points_share(
+    validator_reward: Tokens,
+    points: EraPoints,
+    total_points: EraPoints,
+) -> Tokens {
+    if total_points == 0 {
+        return 0;
+    }
+    validator_reward * points as Tokens / total_points as Tokens
+}
+
+// estimate the payout of every validator that earned points in a completed era
+pub fn estimate_payouts(
+    validator_reward: Tokens,
+    points: &EraPointsMap,
+    summaries: &[(AccountId32, NominatorSummary)],
+    commissions: &BTreeMap<AccountId32, u32>,
+) -> BTreeMap<AccountId32, PayoutEstimate> {
+    let mut estimates = BTreeMap::new();
+    if points.total == 0 {
+        return estimates;
+    }
+
+    for (account_id, era_points) in points.individual.iter() {
+        let payout = points_share(validator_reward, *era_points, points.total);
+        // commission is stored in parts per billion
+        let commission = commissions.get(account_id).copied().unwrap_or_default();
+        let commission = payout * commission as Tokens / 1_000_000_000;
+        let (backing, own) = summaries
+            .iter()
+            .find(|(id, _)| id == account_id)
+            .map_or((0, 0), |(_, s)| (s.total, s.own));
+
+        let mut estimate = PayoutEstimate {
+            payout,
+            commission,
+            backing,
+            ..Default::default()
+        };
+        if backing > 0 {
+            estimate.nominators = estimate.shared() * (backing - own) / backing;
+            estimate.rate = estimate.shared() as f64 / backing as f64;
+        }
+        estimates.insert(account_id.clone(), estimate);
+    }
+    estimates
+}
+
+// return per staked token after commission across all estimated validators
+pub fn average_rate(estimates: &BTreeMap<AccountId32, PayoutEstimate>) -> f64 {
+    let backing: Tokens = estimates.values().map(|e| e.backing).sum();
+    if backing == 0 {
+        return 0.0;
+    }
+    let shared: Tokens = estimates
+        .values()
+        .filter(|e| e.backing > 0)
+        .map(|e| e.shared())
+        .sum();
+    shared as f64 / backing as f64
+}
+
+// annualize a per-era return given the era duration in milliseconds
+pub fn annualize(rate: f64, era_duration: u64) -> Option<f64> {
+    if era_duration == 0 {
+        return None;
+    }
+    Some(rate * YEAR / era_duration as f64)
+}
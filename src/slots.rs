@@ -0,0 +1,115 @@
+use crate::substrate::BabeEpoch;
+
+use blake2::digest::consts::U32;
+use blake2::{Blake2b, Digest};
+use std::collections::BTreeMap;
+
+// authority index assigned to a secondary slot, the blake2-256 hash of the epoch
+// randomness and slot read as a big-endian integer modulo the authority count
+pub fn secondary_slot_author(
+    randomness: &[u8; 32],
+    slot: u64,
+    authorities: usize,
+) -> Option<usize> {
+    if authorities == 0 {
+        return None;
+    }
+
+    let index = secondary_slot_hash(randomness, slot)
+        .iter()
+        .fold(0_u128, |acc, b| {
+            (acc * 256 + *b as u128) % authorities as u128
+        });
+    Some(index as usize)
+}
+
+// blake2-256 hash of the scale encoded (randomness, slot) tuple
+fn secondary_slot_hash(randomness: &[u8; 32], slot: u64) -> [u8; 32] {
+    let mut ctx = Blake2b::<U32>::new();
+    ctx.update(randomness);
+    ctx.update(slot.to_le_bytes());
+    ctx.finalize().into()
+}
+
+// secondary slots of the epoch assigned to the given authority indices, by slot
+pub fn expected_secondary_slots(
+    epoch: &BabeEpoch,
+    authorities: impl Fn(usize) -> bool,
+) -> BTreeMap<u64, usize> {
+    (epoch.start_slot..epoch.start_slot + epoch.duration)
+        .filter_map(|slot| {
+            let index = secondary_slot_author(&epoch.randomness, slot, epoch.authorities)?;
+            authorities(index).then_some((slot, index))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use subxt::ext::codec::Encode;
+
+    fn randomness() -> [u8; 32] {
+        core::array::from_fn(|i| i as u8 + 1)
+    }
+
+    fn epoch(authorities: usize) -> BabeEpoch {
+        BabeEpoch {
+            index: 0,
+            start_slot: 100,
+            duration: 10,
+            randomness: randomness(),
+            authorities,
+        }
+    }
+
+    #[test]
+    fn hashes_scale_encoded_randomness_and_slot() {
+        let encoded = (randomness(), 123_456_789_u64).encode();
+        assert_eq!(encoded.len(), 40);
+        assert_eq!(&encoded[..32], &randomness());
+        assert_eq!(&encoded[32..], &123_456_789_u64.to_le_bytes());
+
+        let hash = secondary_slot_hash(&randomness(), 123_456_789);
+        assert_eq!(hash, <[u8; 32]>::from(Blake2b::<U32>::digest(&encoded)));
+        assert_eq!(
+            crate::utils::format_hex(&hash),
+            "0xb4dc6f5bba9ce714138c2a33edf08335ec7b8e911cb6ec554200920e25a6f4e3"
+        );
+    }
+
+    #[test]
+    fn reads_hash_as_big_endian_modulo_authorities() {
+        // expected indices are the big-endian hash integers reduced by the authority count
+        assert_eq!(secondary_slot_author(&[0; 32], 0, 297), Some(29));
+        assert_eq!(
+            secondary_slot_author(&randomness(), 123_456_789, 7),
+            Some(1)
+        );
+        assert_eq!(
+            secondary_slot_author(&randomness(), 123_456_789, 2_147_483_647),
+            Some(1_717_513_296)
+        );
+        assert_eq!(
+            secondary_slot_author(&[0xff; 32], u64::MAX, 1_000_003),
+            Some(577_892)
+        );
+    }
+
+    #[test]
+    fn assigns_no_author_without_authorities() {
+        assert_eq!(secondary_slot_author(&randomness(), 1, 0), None);
+        assert_eq!(secondary_slot_author(&randomness(), 1, 1), Some(0));
+    }
+
+    #[test]
+    fn expects_epoch_slots_of_selected_authorities() {
+        // authors of slots 100 to 109 are 1, 1, 4, 4, 3, 1, 2, 3, 3, 4
+        let expected = expected_secondary_slots(&epoch(5), |index| index == 1 || index == 3);
+        assert_eq!(
+            expected.into_iter().collect::<Vec<_>>(),
+            vec![(100, 1), (101, 1), (104, 3), (105, 1), (107, 3), (108, 3)]
+        );
+        assert!(expected_secondary_slots(&epoch(0), |_| true).is_empty());
+    }
+}
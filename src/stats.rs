@@ -0,0 +1,61 @@
+// summary statistics over era points of the active set
+
+pub fn mean(values: &[f64]) -> f64 {
+    if values.is_empty() {
+        return 0.0;
+    }
+    values.iter().sum::<f64>() / values.len() as f64
+}
+
+pub fn std_dev(values: &[f64]) -> f64 {
+    if values.is_empty() {
+        return 0.0;
+    }
+    let mean = mean(values);
+    let variance = values.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / values.len() as f64;
+    variance.sqrt()
+}
+
+// linearly interpolated percentile (0-100) of ascending sorted values
+pub fn percentile(sorted: &[f64], percentile: f64) -> f64 {
+    if sorted.is_empty() {
+        return 0.0;
+    }
+    let rank = percentile / 100.0 * (sorted.len() - 1) as f64;
+    let (lower, upper) = (rank.floor() as usize, rank.ceil() as usize);
+    sorted[lower] + (sorted[upper] - sorted[lower]) * (rank - lower as f64)
+}
+
+pub fn median(sorted: &[f64]) -> f64 {
+    percentile(sorted, 50.0)
+}
+
+// share (0-100) of values below the given value, counting ties as half
+pub fn percentile_rank(values: &[f64], value: f64) -> f64 {
+    if values.is_empty() {
+        return 0.0;
+    }
+    let below = values.iter().filter(|v| **v < value).count() as f64;
+    let equal = values.iter().filter(|v| **v == value).count() as f64;
+    (below + 0.5 * equal) / values.len() as f64 * 100.0
+}
+
+// ratio of a value to a reference, zero when there is no reference
+pub fn ratio(value: f64, reference: f64) -> f64 {
+    if reference == 0.0 {
+        return 0.0;
+    }
+    value / reference
+}
+
+pub fn hours(millis: u64) -> f64 {
+    millis as f64 / 3_600_000.0
+}
+
+// rate per hour of a change over the given milliseconds
+pub fn per_hour(delta: f64, millis: u64) -> f64 {
+    if millis == 0 {
+        return 0.0;
+    }
+    delta / hours(millis)
+}
@@ -0,0 +1,182 @@
+use crate::history::{EraRecord, ValidatorEraRecord};
+use crate::substrate::{Era, EraPoints, Tokens};
+
+use serde::{Deserialize, Serialize};
+
+type StoreResult<T> = Result<T, Box<dyn std::error::Error + Send + Sync>>;
+
+// persisted results of a validator in a completed era
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ValidatorEraEntry {
+    pub era: Era,
+    pub active: bool,
+    pub points: EraPoints,
+    pub total_points: EraPoints,
+    pub backing: Tokens,
+    pub own_stake: Tokens,
+    pub nominator_count: u32,
+    pub commission: u32,
+    pub reward: Tokens,
+}
+
+impl ValidatorEraEntry {
+    pub fn new(era: &EraRecord, record: &ValidatorEraRecord) -> Self {
+        Self {
+            era: era.era,
+            active: record.active,
+            points: record.points,
+            total_points: era.total_points,
+            backing: record.backing,
+            own_stake: record.own_stake,
+            nominator_count: record.nominator_count,
+            commission: record.commission,
+            reward: record.reward(era),
+        }
+    }
+}
+
+// persisted reward paid out to a validator or one of its nominators
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct RewardEntry {
+    pub era: Era,
+    pub stash: String,
+    pub amount: Tokens,
+    pub block_number: u32,
+    pub block_hash: String,
+    pub extrinsic_index: u32,
+    pub event_index: u32,
+}
+
+// persisted occurrence of a validator in slashing or offence storage
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SlashingEntry {
+    pub source: String,
+    pub era: Option<Era>,
+    pub amount: Option<Tokens>,
+    pub detail: String,
+}
+
+// embedded on-disk store of per-era validator results
+#[derive(Debug)]
+pub struct Store {
+    eras: sled::Tree,
+    rewards: sled::Tree,
+    slashing: sled::Tree,
+}
+impl Store {
+    // open (or create) the store at the given path
+    pub fn open(path: &str) -> StoreResult<Self> {
+        let db = sled::open(path)?;
+        let eras = db.open_tree("eras")?;
+        let rewards = db.open_tree("rewards")?;
+        let slashing = db.open_tree("slashing")?;
+        Ok(Self {
+            eras,
+            rewards,
+            slashing,
+        })
+    }
+
+    // keys sort by validator address, then by era
+    fn key(address: &str, era: Era) -> Vec<u8> {
+        let mut key = address.as_bytes().to_vec();
+        key.push(b'/');
+        key.extend(era.to_be_bytes());
+        key
+    }
+
+    // persist the results of every validator in a completed era
+    pub fn insert_era(&self, era: &EraRecord) -> StoreResult<()> {
+        for (address, record) in era.validators.iter() {
+            let entry = ValidatorEraEntry::new(era, record);
+            self.eras
+                .insert(Self::key(address, era.era), serde_json::to_vec(&entry)?)?;
+        }
+        self.eras.flush()?;
+        Ok(())
+    }
+
+    // get the results of a validator within the given (inclusive) era range
+    pub fn validator_eras(
+        &self,
+        address: &str,
+        from: Era,
+        to: Era,
+    ) -> StoreResult<Vec<ValidatorEraEntry>> {
+        let mut entries = vec![];
+        for entry in self
+            .eras
+            .range(Self::key(address, from)..=Self::key(address, to))
+        {
+            let (_, value) = entry?;
+            entries.push(serde_json::from_slice(&value)?);
+        }
+        Ok(entries)
+    }
+
+    // persist a reward paid out for the given validator address
+    pub fn insert_reward(&self, address: &str, reward: &RewardEntry) -> StoreResult<()> {
+        let mut key = Self::key(address, reward.era);
+        key.extend(reward.block_number.to_be_bytes());
+        key.extend(reward.event_index.to_be_bytes());
+        self.rewards.insert(key, serde_json::to_vec(reward)?)?;
+        self.rewards.flush()?;
+        Ok(())
+    }
+
+    // get the rewards paid out for a validator within the given (inclusive) era range
+    pub fn validator_rewards(
+        &self,
+        address: &str,
+        from: Era,
+        to: Era,
+    ) -> StoreResult<Vec<RewardEntry>> {
+        let mut prefix = address.as_bytes().to_vec();
+        prefix.push(b'/');
+
+        let mut entries = vec![];
+        for entry in self.rewards.range(Self::key(address, from)..) {
+            let (key, value) = entry?;
+            if !key.starts_with(&prefix) {
+                break;
+            }
+            let reward: RewardEntry = serde_json::from_slice(&value)?;
+            if reward.era > to {
+                break;
+            }
+            entries.push(reward);
+        }
+        Ok(entries)
+    }
+
+    // persist a slashing occurrence of a validator under a unique id, unless already stored
+    pub fn insert_slashing(
+        &self,
+        address: &str,
+        id: &str,
+        entry: &SlashingEntry,
+    ) -> StoreResult<()> {
+        let mut key = address.as_bytes().to_vec();
+        key.push(b'/');
+        key.extend(id.as_bytes());
+        // an entry already stored is kept as first recorded
+        let _ =
+            self.slashing
+                .compare_and_swap(key, None::<&[u8]>, Some(serde_json::to_vec(entry)?))?;
+        self.slashing.flush()?;
+        Ok(())
+    }
+
+    // get every slashing occurrence stored for a validator
+    pub fn validator_slashing(&self, address: &str) -> StoreResult<Vec<SlashingEntry>> {
+        let mut prefix = address.as_bytes().to_vec();
+        prefix.push(b'/');
+
+        let mut entries = vec![];
+        for entry in self.slashing.scan_prefix(prefix) {
+            let (_, value) = entry?;
+            entries.push(serde_json::from_slice(&value)?);
+        }
+        Ok(entries)
+    }
+}
@@ -1,12 +1,22 @@
 use crate::constants::Network;
+use crate::phragmen::Voter;
 use crate::utils::serialize_address;
 use log::{info, warn};
+use std::collections::BTreeMap;
 use subxt::{
+    backend::{
+        legacy::{rpc_methods::NumberOrHex, LegacyRpcMethods},
+        rpc::RpcClient,
+    },
+    config::substrate::DigestItem,
     dynamic::{DecodedValueThunk, Value},
-    storage::{DefaultAddress, StorageKey},
-    utils::{AccountId32, Yes},
+    events::{Events, StaticEvent},
+    ext::scale_value::{Composite, ValueDef},
+    storage::{DefaultAddress, Storage, StorageKey},
+    utils::{AccountId32, Yes, H256},
     OnlineClient, PolkadotConfig,
 };
+use subxt_signer::sr25519::Keypair;
 
 // substrate rpc return types
 pub type Era = u32;
@@ -20,19 +30,140 @@ pub fn tokens_to_i64(tokens: Tokens) -> i64 {
 
 #[derive(Debug, scale_decode::DecodeAsType, Default)]
 pub struct EraPointsMap {
+    pub total: EraPoints,
     pub individual: Vec<(AccountId32, EraPoints)>,
 }
 
-impl EraPointsMap {
-    pub fn default() -> Self {
-        Self { individual: vec![] }
-    }
-}
-
 #[derive(Debug, Default, scale_decode::DecodeAsType)]
 pub struct NominatorSummary {
     pub total: Tokens,
+    pub own: Tokens,
     pub nominator_count: u32,
+    pub page_count: u32,
+}
+
+#[derive(Debug, scale_decode::DecodeAsType)]
+pub struct IndividualExposure {
+    pub who: AccountId32,
+    pub value: Tokens,
+}
+
+#[derive(Debug, scale_decode::DecodeAsType)]
+pub struct ExposurePage {
+    pub page_total: Tokens,
+    pub others: Vec<IndividualExposure>,
+}
+
+#[derive(Debug, scale_decode::DecodeAsType)]
+pub struct Nominations {
+    pub targets: Vec<AccountId32>,
+}
+
+#[derive(Debug, scale_decode::DecodeAsType)]
+pub struct StakingLedger {
+    pub stash: AccountId32,
+    pub active: Tokens,
+}
+
+// election snapshot of the ongoing election round
+#[derive(Debug, Default)]
+pub struct ElectionSnapshot {
+    pub round: u32,
+    pub voters: Vec<Voter>,
+    pub targets: Vec<AccountId32>,
+    pub desired_targets: u32,
+}
+
+#[derive(Debug, Default, scale_decode::DecodeAsType)]
+pub struct ElectionScore {
+    pub minimal_stake: Tokens,
+    pub sum_stake: Tokens,
+}
+
+// progress of the active era and its sessions, timestamps in milliseconds
+#[derive(Debug, Default, Clone)]
+pub struct EraProgress {
+    pub era: Era,
+    pub start: u64,
+    pub now: u64,
+    pub session_index: Option<u32>,
+    pub session_in_era: Option<u32>,
+    pub era_duration: Option<u64>,
+    pub remaining: Option<u64>,
+}
+
+impl EraProgress {
+    pub fn elapsed(&self) -> u64 {
+        self.now.saturating_sub(self.start)
+    }
+}
+
+// nominators (and their active bond) of every nominated validator
+pub type NominationIndex = BTreeMap<AccountId32, Vec<(AccountId32, Tokens)>>;
+
+// unclaimed payout pages of every tracked validator address, keyed by era
+pub type UnclaimedPayouts = BTreeMap<String, BTreeMap<Era, Vec<u32>>>;
+
+// extract the trailing account id of a storage map key
+pub fn account_from_key(key_bytes: &[u8]) -> Option<AccountId32> {
+    let offset = key_bytes.len().checked_sub(32)?;
+    let bytes: [u8; 32] = key_bytes[offset..].try_into().ok()?;
+    Some(AccountId32(bytes))
+}
+
+// extract the era of a storage map keyed by era first (hashed with twox64concat)
+pub fn era_from_key(key_bytes: &[u8]) -> Option<Era> {
+    let bytes: [u8; 4] = key_bytes.get(40..44)?.try_into().ok()?;
+    Some(Era::from_le_bytes(bytes))
+}
+
+// find the first byte array within a dynamically decoded value
+fn first_bytes<T>(value: &Value<T>) -> Option<Vec<u8>> {
+    let ValueDef::Composite(composite) = &value.value else {
+        return None;
+    };
+    let bytes: Option<Vec<u8>> = composite
+        .values()
+        .map(|v| v.as_u128().and_then(|b| u8::try_from(b).ok()))
+        .collect();
+    match bytes.filter(|b| !b.is_empty()) {
+        Some(bytes) => Some(bytes),
+        None => composite.values().next().and_then(first_bytes),
+    }
+}
+
+// find the first account id within a dynamically decoded value
+fn first_account<T>(value: &Value<T>) -> Option<AccountId32> {
+    let bytes: [u8; 32] = first_bytes(value)?.try_into().ok()?;
+    Some(AccountId32(bytes))
+}
+
+// public session keys by key type, decoded from the runtime's session keys struct
+pub type SessionKeys = BTreeMap<String, Vec<u8>>;
+
+fn session_keys<T>(value: &Value<T>) -> Option<SessionKeys> {
+    let ValueDef::Composite(Composite::Named(fields)) = &value.value else {
+        return None;
+    };
+    fields
+        .iter()
+        .map(|(key_type, key)| Some((key_type.clone(), first_bytes(key)?)))
+        .collect()
+}
+
+// slash of a validator and its nominators, deferred until applied
+#[derive(Debug, Clone, scale_decode::DecodeAsType)]
+pub struct UnappliedSlash {
+    pub validator: AccountId32,
+    pub own: Tokens,
+    pub others: Vec<(AccountId32, Tokens)>,
+}
+
+#[derive(Debug, Default, scale_decode::DecodeAsType)]
+pub struct SlashingSpans {
+    pub span_index: u32,
+    pub last_nonzero_slash: Era,
+    pub prior: Vec<Era>,
 }
 
 //new stuff_________________________
@@ -42,11 +173,102 @@ pub struct ActiveEraInfo {
     pub start: Option<u64>,
 }
 
+// fee paid for a submitted extrinsic
+#[derive(Debug, scale_decode::DecodeAsType)]
+pub struct TransactionFeePaid {
+    pub actual_fee: Tokens,
+}
+impl StaticEvent for TransactionFeePaid {
+    const PALLET: &'static str = "TransactionPayment";
+    const EVENT: &'static str = "TransactionFeePaid";
+}
+
+// start of a validator payout, followed by a Rewarded event per recipient
+#[derive(Debug, scale_decode::DecodeAsType)]
+pub struct PayoutStarted {
+    pub era_index: Era,
+    pub validator_stash: AccountId32,
+}
+impl StaticEvent for PayoutStarted {
+    const PALLET: &'static str = "Staking";
+    const EVENT: &'static str = "PayoutStarted";
+}
+
+#[derive(Debug, scale_decode::DecodeAsType)]
+pub struct Rewarded {
+    pub stash: AccountId32,
+    pub amount: Tokens,
+}
+impl StaticEvent for Rewarded {
+    const PALLET: &'static str = "Staking";
+    const EVENT: &'static str = "Rewarded";
+}
+
+// validator preferences of an era, commission is stored in parts per billion
+#[derive(Debug, scale_decode::DecodeAsType)]
+struct ValidatorPrefs {
+    commission: u32,
+}
+
+// outcome of a finalized payout extrinsic
+#[derive(Debug, Clone, Default)]
+pub struct PayoutReceipt {
+    pub block_hash: H256,
+    pub success: bool,
+    pub fee: Tokens,
+}
+
+// consensus engine id of babe digests
+const BABE_ENGINE_ID: [u8; 4] = *b"BABE";
+
+// slot claim of a block author, decoded from the babe pre-runtime digest
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BabeClaim {
+    // primary claims are won by vrf, secondary ones are assigned round robin
+    pub primary: bool,
+    pub authority_index: u32,
+    pub slot: u64,
+}
+
+impl BabeClaim {
+    // the digest starts with the claim variant, followed by the authority index and slot
+    fn decode(data: &[u8]) -> Option<Self> {
+        let primary = match data.first()? {
+            1 => true,
+            2 | 3 => false,
+            _ => return None,
+        };
+        let authority_index = u32::from_le_bytes(data.get(1..5)?.try_into().ok()?);
+        let slot = u64::from_le_bytes(data.get(5..13)?.try_into().ok()?);
+        Some(Self {
+            primary,
+            authority_index,
+            slot,
+        })
+    }
+}
+
+#[derive(Debug, scale_decode::DecodeAsType)]
+struct Slot(u64);
+
+// babe epoch the secondary slot assignments are derived from
+#[derive(Debug, Clone)]
+pub struct BabeEpoch {
+    pub index: u64,
+    pub start_slot: u64,
+    pub duration: u64,
+    pub randomness: [u8; 32],
+    pub authorities: usize,
+}
+
 // substrate rpc actions
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct SubstrateRPC {
     network: Network,
     client: OnlineClient<PolkadotConfig>,
+    rpc: LegacyRpcMethods<PolkadotConfig>,
+    // block storage queries are pinned to, latest finalized block if unset
+    at: Option<H256>,
 }
 impl SubstrateRPC {
     // instantiate a new substrate rpc client
@@ -54,15 +276,46 @@ impl SubstrateRPC {
         network: Network,
         rpc_url: &str,
     ) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
-        let client = OnlineClient::<PolkadotConfig>::from_url(rpc_url).await?;
-        Ok(Self { network, client })
+        let rpc_client = RpcClient::from_url(rpc_url).await?;
+        let client = OnlineClient::<PolkadotConfig>::from_rpc_client(rpc_client.clone()).await?;
+        Ok(Self {
+            network,
+            client,
+            rpc: LegacyRpcMethods::new(rpc_client),
+            at: None,
+        })
+    }
+
+    // pin all storage queries to the given block
+    pub fn at_block(&self, hash: H256) -> Self {
+        Self {
+            at: Some(hash),
+            ..self.clone()
+        }
+    }
+
+    async fn storage(
+        &self,
+    ) -> Result<Storage<PolkadotConfig, OnlineClient<PolkadotConfig>>, subxt::Error> {
+        match self.at {
+            Some(hash) => Ok(self.client.storage().at(hash)),
+            None => self.client.storage().at_latest().await,
+        }
     }
 
     async fn query_storage<T: StorageKey>(
         &self,
         query: DefaultAddress<T, DecodedValueThunk, Yes, Yes, Yes>,
     ) -> Option<DecodedValueThunk> {
-        let storage = match self.client.storage().at_latest().await {
+        self.query_storage_entry(query).await.flatten()
+    }
+
+    // query storage telling missing entries (Some(None)) apart from failed queries (None)
+    async fn query_storage_entry<T: StorageKey>(
+        &self,
+        query: DefaultAddress<T, DecodedValueThunk, Yes, Yes, Yes>,
+    ) -> Option<Option<DecodedValueThunk>> {
+        let storage = match self.storage().await {
             Ok(s) => s,
             Err(e) => {
                 warn!("Storage RPC unavailable: {e}");
@@ -71,7 +324,7 @@ impl SubstrateRPC {
         };
 
         match storage.fetch(&query).await {
-            Ok(res) => res,
+            Ok(res) => Some(res),
             Err(e) => {
                 warn!("Storage query failed: {e}");
                 None
@@ -79,6 +332,108 @@ impl SubstrateRPC {
         }
     }
 
+    async fn iter_storage(
+        &self,
+        query: DefaultAddress<Vec<Value>, DecodedValueThunk, Yes, Yes, Yes>,
+    ) -> Option<Vec<(Vec<u8>, DecodedValueThunk)>> {
+        let storage = match self.storage().await {
+            Ok(s) => s,
+            Err(e) => {
+                warn!("Storage RPC unavailable: {e}");
+                return None;
+            }
+        };
+
+        let mut entries = match storage.iter(query).await {
+            Ok(entries) => entries,
+            Err(e) => {
+                warn!("Storage iteration failed: {e}");
+                return None;
+            }
+        };
+
+        let mut res = vec![];
+        while let Some(entry) = entries.next().await {
+            match entry {
+                Ok(kv) => res.push((kv.key_bytes, kv.value)),
+                Err(e) => {
+                    warn!("Storage iteration failed: {e}");
+                    return None;
+                }
+            }
+        }
+        Some(res)
+    }
+
+    // like query_storage, but absent values decode as the storage default
+    async fn query_storage_or_default<T: StorageKey>(
+        &self,
+        query: DefaultAddress<T, DecodedValueThunk, Yes, Yes, Yes>,
+    ) -> Option<DecodedValueThunk> {
+        let storage = match self.storage().await {
+            Ok(s) => s,
+            Err(e) => {
+                warn!("Storage RPC unavailable: {e}");
+                return None;
+            }
+        };
+
+        match storage.fetch_or_default(&query).await {
+            Ok(res) => Some(res),
+            Err(e) => {
+                warn!("Storage query failed: {e}");
+                None
+            }
+        }
+    }
+
+    async fn query_decoded<T: scale_decode::DecodeAsType, K: StorageKey>(
+        &self,
+        query: DefaultAddress<K, DecodedValueThunk, Yes, Yes, Yes>,
+    ) -> Option<T> {
+        match self.query_storage(query).await?.as_type() {
+            Ok(res) => Some(res),
+            Err(e) => {
+                warn!("Storage decoding failed: {e}");
+                None
+            }
+        }
+    }
+
+    fn query_constant<T: scale_decode::DecodeAsType>(
+        &self,
+        query: subxt::constants::DefaultAddress<DecodedValueThunk>,
+    ) -> Option<T> {
+        match self.client.constants().at(&query) {
+            Ok(res) => match res.as_type() {
+                Ok(res) => Some(res),
+                Err(e) => {
+                    warn!("Constant decoding failed: {e}");
+                    None
+                }
+            },
+            Err(e) => {
+                warn!("Constant query failed: {e}");
+                None
+            }
+        }
+    }
+
+    // check whether the runtime includes the given pallet
+    pub fn has_pallet(&self, pallet: &str) -> bool {
+        self.client.metadata().pallet_by_name(pallet).is_some()
+    }
+
+    // check whether the runtime includes the given storage entry
+    pub fn has_storage(&self, pallet: &str, entry: &str) -> bool {
+        self.client
+            .metadata()
+            .pallet_by_name(pallet)
+            .and_then(|p| p.storage())
+            .and_then(|s| s.entry_by_name(entry))
+            .is_some()
+    }
+
     // get ongoing era number
     // get ongoing era number
     pub async fn get_current_era(&self) -> Option<Era> {
@@ -104,6 +459,135 @@ impl SubstrateRPC {
         }
     }
 
+    // get the active era number, Some(None) before staking has started
+    pub async fn get_active_era_entry(&self) -> Option<Option<Era>> {
+        let query = subxt::dynamic::storage("Staking", "ActiveEra", ());
+
+        match self.query_storage_entry(query).await? {
+            Some(active_era) => match active_era.as_type::<ActiveEraInfo>() {
+                Ok(active_era_info) => Some(Some(active_era_info.index)),
+                Err(e) => {
+                    warn!("Failed to decode ActiveEra: {e}");
+                    None
+                }
+            },
+            None => Some(None),
+        }
+    }
+
+    // get the progress of the active era using the session and babe schedule of the
+    // relay chain, eras and their start sessions are read from the staking chain
+    pub async fn get_era_progress(&self, relay: Option<&SubstrateRPC>) -> Option<EraProgress> {
+        let active_era = self
+            .query_storage(subxt::dynamic::storage("Staking", "ActiveEra", ()))
+            .await?;
+        let ActiveEraInfo { index: era, start } = match active_era.as_type() {
+            Ok(v) => v,
+            Err(e) => {
+                warn!("Failed to decode ActiveEra: {e}");
+                return None;
+            }
+        };
+        let now = self.get_timestamp().await?;
+
+        let mut progress = EraProgress {
+            era,
+            start: start?,
+            now,
+            ..Default::default()
+        };
+
+        // session schedule is only available on relay chains running session and babe
+        let Some(relay) =
+            relay.filter(|relay| relay.has_pallet("Session") && relay.has_pallet("Babe"))
+        else {
+            info!("Fetched era progress without session data: {progress:?}");
+            return Some(progress);
+        };
+
+        let session_index = relay.get_session_index().await;
+        let start_session: Option<u32> = self
+            .query_decoded(subxt::dynamic::storage(
+                "Staking",
+                "ErasStartSessionIndex",
+                vec![era.into()],
+            ))
+            .await;
+        progress.session_index = session_index;
+        progress.session_in_era = session_index
+            .zip(start_session)
+            .map(|(current, start)| current.saturating_sub(start));
+
+        let sessions_per_era: Option<u32> =
+            self.query_constant(subxt::dynamic::constant("Staking", "SessionsPerEra"));
+        let epoch_duration: Option<u64> =
+            relay.query_constant(subxt::dynamic::constant("Babe", "EpochDuration"));
+        let slot_duration: Option<u64> =
+            relay.query_constant(subxt::dynamic::constant("Babe", "ExpectedBlockTime"));
+
+        let (Some(sessions_per_era), Some(epoch_duration), Some(slot_duration)) =
+            (sessions_per_era, epoch_duration, slot_duration)
+        else {
+            return Some(progress);
+        };
+        let session_duration = epoch_duration * slot_duration;
+        progress.era_duration = Some(sessions_per_era as u64 * session_duration);
+
+        // slots elapsed within the current epoch (session)
+        let (Some(epoch_index), Some(genesis_slot), Some(current_slot)) = (
+            relay
+                .query_decoded::<u64, _>(subxt::dynamic::storage("Babe", "EpochIndex", ()))
+                .await,
+            relay
+                .query_decoded::<u64, _>(subxt::dynamic::storage("Babe", "GenesisSlot", ()))
+                .await,
+            relay
+                .query_decoded::<u64, _>(subxt::dynamic::storage("Babe", "CurrentSlot", ()))
+                .await,
+        ) else {
+            return Some(progress);
+        };
+        let epoch_start_slot = genesis_slot + epoch_index * epoch_duration;
+        let session_elapsed = current_slot.saturating_sub(epoch_start_slot) * slot_duration;
+
+        if let Some(session_in_era) = progress.session_in_era {
+            let sessions_left = (sessions_per_era as u64).saturating_sub(session_in_era as u64);
+            progress.remaining =
+                Some((sessions_left * session_duration).saturating_sub(session_elapsed));
+        }
+
+        info!("Fetched era progress: {progress:?}");
+        Some(progress)
+    }
+
+    // get the latest block number
+    pub async fn get_block_number(&self) -> Option<u32> {
+        let query = subxt::dynamic::storage("System", "Number", ());
+
+        if let Some(block_number) = self.query_storage(query).await {
+            let block_number: u32 = block_number.as_type().unwrap();
+            info!("Fetched block number: {block_number}");
+            Some(block_number)
+        } else {
+            warn!("Unable to fetch block number!");
+            None
+        }
+    }
+
+    // get the latest planned era number, ahead of the active era once the next era is elected
+    pub async fn get_planned_era(&self) -> Option<Era> {
+        let query = subxt::dynamic::storage("Staking", "CurrentEra", ());
+
+        if let Some(planned_era) = self.query_storage(query).await {
+            let planned_era: Era = planned_era.as_type().unwrap();
+            info!("Fetched planned era: {planned_era}");
+            Some(planned_era)
+        } else {
+            warn!("Unable to fetch planned era!");
+            None
+        }
+    }
+
     // get era points earned in the given era by all validators
     pub async fn get_all_era_points(&self, era: Era) -> Option<EraPointsMap> {
         let query = subxt::dynamic::storage("Staking", "ErasRewardPoints", vec![era.into()]);
@@ -146,6 +630,382 @@ impl SubstrateRPC {
         }
     }
 
+    // get the nominator summary of a validator at the given era, Some(None) if it is not elected
+    pub async fn get_elected_summary(
+        &self,
+        era: Era,
+        account_id: &AccountId32,
+    ) -> Option<Option<NominatorSummary>> {
+        let query = subxt::dynamic::storage(
+            "Staking",
+            "ErasStakersOverview",
+            vec![era.into(), Value::from_bytes(account_id)],
+        );
+
+        let Some(entry) = self.query_storage_entry(query).await else {
+            warn!(
+                "Unable to fetch nominator summary for {} at era {era}!",
+                serialize_address(&self.network, account_id)
+            );
+            return None;
+        };
+        match entry.map(|summary| summary.as_type::<NominatorSummary>()) {
+            Some(Ok(summary)) => Some(Some(summary)),
+            Some(Err(e)) => {
+                warn!("Failed to decode nominator summary: {e}");
+                None
+            }
+            None => Some(None),
+        }
+    }
+
+    // get the nominator summary of every elected validator at the given era
+    pub async fn get_all_nominator_summaries(
+        &self,
+        era: Era,
+    ) -> Option<Vec<(AccountId32, NominatorSummary)>> {
+        let query = subxt::dynamic::storage("Staking", "ErasStakersOverview", vec![era.into()]);
+
+        let summaries: Vec<(AccountId32, NominatorSummary)> = self
+            .iter_storage(query)
+            .await?
+            .into_iter()
+            .filter_map(|(key, summary)| Some((account_from_key(&key)?, summary.as_type().ok()?)))
+            .collect();
+
+        if summaries.is_empty() {
+            warn!("Unable to fetch nominator summaries at era {era}!");
+            None
+        } else {
+            info!(
+                "Fetched {} nominator summaries at era {era}",
+                summaries.len()
+            );
+            Some(summaries)
+        }
+    }
+
+    // get a single page of nominator exposure for the given account at the given era
+    pub async fn get_exposure_page(
+        &self,
+        era: Era,
+        account_id: &AccountId32,
+        page: u32,
+    ) -> Option<ExposurePage> {
+        let query = subxt::dynamic::storage(
+            "Staking",
+            "ErasStakersPaged",
+            vec![era.into(), Value::from_bytes(account_id), page.into()],
+        );
+
+        if let Some(exposure_page) = self.query_storage(query).await {
+            let exposure_page: ExposurePage = exposure_page.as_type().unwrap();
+            info!(
+                "Fetched exposure page {page} for {} at era {era}",
+                serialize_address(&self.network, account_id)
+            );
+            Some(exposure_page)
+        } else {
+            warn!(
+                "Unable to fetch exposure page {page} for {} at era {era}!",
+                serialize_address(&self.network, account_id)
+            );
+            None
+        }
+    }
+
+    // get the maximum number of nominators paid out per exposure page
+    pub fn get_max_exposure_page_size(&self) -> Option<u32> {
+        let query = subxt::dynamic::constant("Staking", "MaxExposurePageSize");
+
+        self.query_constant(query)
+    }
+
+    // get the nominators targeting each validator along with their active bond
+    pub async fn get_nomination_index(&self) -> Option<NominationIndex> {
+        let ledgers = self
+            .iter_storage(subxt::dynamic::storage("Staking", "Ledger", vec![]))
+            .await?;
+
+        let mut bonded: BTreeMap<AccountId32, Tokens> = BTreeMap::new();
+        for (_, ledger) in ledgers {
+            if let Ok(ledger) = ledger.as_type::<StakingLedger>() {
+                bonded.insert(ledger.stash, ledger.active);
+            }
+        }
+
+        let nominators = self
+            .iter_storage(subxt::dynamic::storage("Staking", "Nominators", vec![]))
+            .await?;
+
+        let mut index = NominationIndex::new();
+        for (key, nominations) in nominators.iter() {
+            let (Some(nominator), Ok(nominations)) =
+                (account_from_key(key), nominations.as_type::<Nominations>())
+            else {
+                continue;
+            };
+
+            let stake = bonded.get(&nominator).copied().unwrap_or_default();
+            for target in nominations.targets {
+                index
+                    .entry(target)
+                    .or_default()
+                    .push((nominator.clone(), stake));
+            }
+        }
+
+        info!(
+            "Fetched nomination index: {} nominators, {} validators",
+            nominators.len(),
+            index.len()
+        );
+        Some(index)
+    }
+
+    // get the name of the election provider pallet in use
+    fn election_pallet(&self) -> Option<&'static str> {
+        ["ElectionProviderMultiPhase", "MultiBlockElection"]
+            .into_iter()
+            .find(|pallet| self.has_pallet(pallet))
+    }
+
+    // get the index of the ongoing election round
+    pub async fn get_election_round(&self) -> Option<u32> {
+        let Some(pallet) = self.election_pallet() else {
+            warn!("No supported election provider pallet found!");
+            return None;
+        };
+        let query = subxt::dynamic::storage(pallet, "Round", ());
+
+        if let Some(round) = self.query_storage(query).await {
+            let round: u32 = round.as_type().unwrap();
+            info!("Fetched election round: {round}");
+            Some(round)
+        } else {
+            warn!("Unable to fetch election round!");
+            None
+        }
+    }
+
+    // get the name of the current election phase
+    pub async fn get_election_phase(&self) -> Option<String> {
+        let pallet = self.election_pallet()?;
+        let query = subxt::dynamic::storage(pallet, "CurrentPhase", ());
+
+        let phase = self
+            .query_storage(query)
+            .await
+            .and_then(|phase| phase.to_value().ok());
+        if let Some(ValueDef::Variant(phase)) = phase.map(|p| p.value) {
+            info!("Fetched election phase: {}", phase.name);
+            Some(phase.name)
+        } else {
+            warn!("Unable to fetch election phase!");
+            None
+        }
+    }
+
+    // get the score of the solution queued for the ongoing election round,
+    // Some(None) if no solution is queued
+    pub async fn get_queued_solution_score(&self) -> Option<Option<ElectionScore>> {
+        let score = match self.election_pallet()? {
+            "ElectionProviderMultiPhase" => {
+                #[derive(scale_decode::DecodeAsType)]
+                struct ReadySolution {
+                    score: ElectionScore,
+                }
+
+                let query =
+                    subxt::dynamic::storage("ElectionProviderMultiPhase", "QueuedSolution", ());
+                match self.query_storage_entry(query).await? {
+                    Some(solution) => Some(solution.as_type::<ReadySolution>().ok()?.score),
+                    None => None,
+                }
+            }
+            _ => {
+                let round = self.get_election_round().await?;
+                let query = subxt::dynamic::storage(
+                    "MultiBlockElectionVerifier",
+                    "QueuedSolutionScore",
+                    vec![round.into()],
+                );
+                match self.query_storage_entry(query).await? {
+                    Some(score) => Some(score.as_type().ok()?),
+                    None => None,
+                }
+            }
+        };
+
+        info!("Fetched queued solution score: {score:?}");
+        Some(score)
+    }
+
+    // get the number of signed solutions submitted in the ongoing election round
+    pub async fn get_signed_submission_count(&self) -> Option<u32> {
+        let query = match self.election_pallet()? {
+            "ElectionProviderMultiPhase" => subxt::dynamic::storage(
+                "ElectionProviderMultiPhase",
+                "SignedSubmissionIndices",
+                vec![],
+            ),
+            _ => {
+                let round = self.get_election_round().await?;
+                subxt::dynamic::storage(
+                    "MultiBlockElectionSigned",
+                    "SortedScores",
+                    vec![round.into()],
+                )
+            }
+        };
+
+        // no submissions stored yet
+        let Some(submissions) = self.query_storage_entry(query).await? else {
+            return Some(0);
+        };
+        match submissions.to_value().map(|s| s.value) {
+            Ok(ValueDef::Composite(submissions)) => {
+                info!("Fetched signed submission count: {}", submissions.len());
+                Some(submissions.len() as u32)
+            }
+            _ => {
+                warn!("Unable to decode signed submissions!");
+                None
+            }
+        }
+    }
+
+    // get the snapshot of the ongoing election, if one has been taken
+    pub async fn get_election_snapshot(&self) -> Option<ElectionSnapshot> {
+        match self.election_pallet() {
+            Some("ElectionProviderMultiPhase") => self.get_multi_phase_snapshot().await,
+            Some(_) => self.get_multi_block_snapshot().await,
+            None => {
+                warn!("No supported election provider pallet found!");
+                None
+            }
+        }
+    }
+
+    async fn get_multi_phase_snapshot(&self) -> Option<ElectionSnapshot> {
+        #[derive(scale_decode::DecodeAsType)]
+        struct RoundSnapshot {
+            voters: Vec<Voter>,
+            targets: Vec<AccountId32>,
+        }
+
+        let pallet = "ElectionProviderMultiPhase";
+        let round = self
+            .query_storage(subxt::dynamic::storage(pallet, "Round", ()))
+            .await?
+            .as_type()
+            .ok()?;
+        let snapshot: RoundSnapshot = self
+            .query_storage(subxt::dynamic::storage(pallet, "Snapshot", ()))
+            .await?
+            .as_type()
+            .ok()?;
+        let desired_targets = self
+            .query_storage(subxt::dynamic::storage(pallet, "DesiredTargets", ()))
+            .await?
+            .as_type()
+            .ok()?;
+
+        info!(
+            "Fetched election snapshot for round {round}: {} voters, {} targets",
+            snapshot.voters.len(),
+            snapshot.targets.len()
+        );
+        Some(ElectionSnapshot {
+            round,
+            voters: snapshot.voters,
+            targets: snapshot.targets,
+            desired_targets,
+        })
+    }
+
+    async fn get_multi_block_snapshot(&self) -> Option<ElectionSnapshot> {
+        let pallet = "MultiBlockElection";
+        // the snapshot is taken one page per block, it is only complete once the
+        // election has moved past the snapshot phase
+        match self.get_election_phase().await?.as_str() {
+            "Off" | "Snapshot" => {
+                info!("Election snapshot not complete yet");
+                return None;
+            }
+            _ => {}
+        }
+        let round: u32 = self
+            .query_storage(subxt::dynamic::storage(pallet, "Round", ()))
+            .await?
+            .as_type()
+            .ok()?;
+
+        let mut voters = vec![];
+        for (_, page) in self
+            .iter_storage(subxt::dynamic::storage(
+                pallet,
+                "PagedVoterSnapshot",
+                vec![round.into()],
+            ))
+            .await?
+        {
+            voters.extend(page.as_type::<Vec<Voter>>().ok()?);
+        }
+
+        let mut targets = vec![];
+        for (_, page) in self
+            .iter_storage(subxt::dynamic::storage(
+                pallet,
+                "PagedTargetSnapshot",
+                vec![round.into()],
+            ))
+            .await?
+        {
+            targets.extend(page.as_type::<Vec<AccountId32>>().ok()?);
+        }
+
+        if voters.is_empty() || targets.is_empty() {
+            return None;
+        }
+
+        let desired_targets = self
+            .query_storage(subxt::dynamic::storage(
+                pallet,
+                "DesiredTargets",
+                vec![round.into()],
+            ))
+            .await?
+            .as_type()
+            .ok()?;
+
+        info!(
+            "Fetched election snapshot for round {round}: {} voters, {} targets",
+            voters.len(),
+            targets.len()
+        );
+        Some(ElectionSnapshot {
+            round,
+            voters,
+            targets,
+            desired_targets,
+        })
+    }
+
+    // get the total issuance of the native token
+    pub async fn get_total_issuance(&self) -> Option<Tokens> {
+        let query = subxt::dynamic::storage("Balances", "TotalIssuance", ());
+
+        if let Some(total_issuance) = self.query_storage(query).await {
+            let total_issuance: Tokens = total_issuance.as_type().unwrap();
+            info!("Fetched total issuance: {total_issuance}");
+            Some(total_issuance)
+        } else {
+            warn!("Unable to fetch total issuance!");
+            None
+        }
+    }
+
     // get the minimum active stake of the last successful election
     pub async fn get_minimum_active_stake(&self) -> Option<Tokens> {
         let query = subxt::dynamic::storage("Staking", "MinimumActiveStake", ());
@@ -173,4 +1033,588 @@ impl SubstrateRPC {
             None
         }
     }
+
+    // get the total validator reward payout for the given era
+    pub async fn get_validator_reward(&self, era: Era) -> Option<Tokens> {
+        let query = subxt::dynamic::storage("Staking", "ErasValidatorReward", vec![era.into()]);
+
+        if let Some(validator_reward) = self.query_storage(query).await {
+            let validator_reward: Tokens = validator_reward.as_type().unwrap();
+            info!("Fetched validator reward at era {era}: {validator_reward}");
+            Some(validator_reward)
+        } else {
+            warn!("Unable to fetch validator reward at era {era}!");
+            None
+        }
+    }
+
+    // get the number of eras for which staking history is kept
+    pub fn get_history_depth(&self) -> Option<u32> {
+        let query = subxt::dynamic::constant("Staking", "HistoryDepth");
+
+        self.query_constant(query)
+    }
+
+    // get the commission (in parts per billion) of the given account at the given era,
+    // Some(None) if it had no preferences recorded
+    pub async fn get_validator_commission(
+        &self,
+        era: Era,
+        account_id: &AccountId32,
+    ) -> Option<Option<u32>> {
+        let query = subxt::dynamic::storage(
+            "Staking",
+            "ErasValidatorPrefs",
+            vec![era.into(), Value::from_bytes(account_id)],
+        );
+
+        let Some(entry) = self.query_storage_entry(query).await else {
+            warn!(
+                "Unable to fetch commission for {} at era {era}!",
+                serialize_address(&self.network, account_id)
+            );
+            return None;
+        };
+        match entry.map(|prefs| prefs.as_type::<ValidatorPrefs>()) {
+            Some(Ok(prefs)) => {
+                info!(
+                    "Fetched commission for {} at era {era}",
+                    serialize_address(&self.network, account_id)
+                );
+                Some(Some(prefs.commission))
+            }
+            Some(Err(e)) => {
+                warn!("Failed to decode validator prefs: {e}");
+                None
+            }
+            None => Some(None),
+        }
+    }
+
+    // get the commission (in parts per billion) of every validator at the given era
+    pub async fn get_all_validator_commissions(
+        &self,
+        era: Era,
+    ) -> Option<BTreeMap<AccountId32, u32>> {
+        let query = subxt::dynamic::storage("Staking", "ErasValidatorPrefs", vec![era.into()]);
+
+        let commissions: BTreeMap<AccountId32, u32> = self
+            .iter_storage(query)
+            .await?
+            .into_iter()
+            .filter_map(|(key, prefs)| {
+                let prefs: ValidatorPrefs = prefs.as_type().ok()?;
+                Some((account_from_key(&key)?, prefs.commission))
+            })
+            .collect();
+
+        if commissions.is_empty() {
+            warn!("Unable to fetch validator commissions at era {era}!");
+            None
+        } else {
+            info!(
+                "Fetched {} validator commissions at era {era}",
+                commissions.len()
+            );
+            Some(commissions)
+        }
+    }
+
+    // get the exposure pages of the given account already paid out for the given era
+    pub async fn get_claimed_pages(&self, era: Era, account_id: &AccountId32) -> Option<Vec<u32>> {
+        let query = subxt::dynamic::storage(
+            "Staking",
+            "ClaimedRewards",
+            vec![era.into(), Value::from_bytes(account_id)],
+        );
+
+        if let Some(claimed) = self.query_storage_or_default(query).await {
+            let claimed: Vec<u32> = claimed.as_type().ok()?;
+            info!(
+                "Fetched claimed pages for {} at era {era}",
+                serialize_address(&self.network, account_id)
+            );
+            Some(claimed)
+        } else {
+            warn!(
+                "Unable to fetch claimed pages for {} at era {era}!",
+                serialize_address(&self.network, account_id)
+            );
+            None
+        }
+    }
+
+    // submit payouts of the given validator pages in a single batch_all, waiting for finalization
+    pub async fn submit_payouts(
+        &self,
+        signer: &Keypair,
+        payouts: &[(AccountId32, Era, u32)],
+    ) -> Result<PayoutReceipt, Box<dyn std::error::Error + Send + Sync>> {
+        let calls: Vec<Value> = payouts
+            .iter()
+            .map(|(validator, era, page)| {
+                Value::unnamed_variant(
+                    "Staking",
+                    [Value::named_variant(
+                        "payout_stakers_by_page",
+                        [
+                            ("validator_stash", Value::from_bytes(validator)),
+                            ("era", (*era).into()),
+                            ("page", (*page).into()),
+                        ],
+                    )],
+                )
+            })
+            .collect();
+        let batch = subxt::dynamic::tx(
+            "Utility",
+            "batch_all",
+            vec![Value::unnamed_composite(calls)],
+        );
+
+        let in_block = self
+            .client
+            .tx()
+            .sign_and_submit_then_watch_default(&batch, signer)
+            .await?
+            .wait_for_finalized()
+            .await?;
+        let events = in_block.fetch_events().await?;
+
+        let success = events.iter().flatten().any(|event| {
+            event.pallet_name() == "System" && event.variant_name() == "ExtrinsicSuccess"
+        });
+        let fee = events
+            .find_first::<TransactionFeePaid>()?
+            .map_or(0, |paid| paid.actual_fee);
+        info!(
+            "Submitted {} payouts in block {:?}, success: {success}",
+            payouts.len(),
+            in_block.block_hash()
+        );
+
+        Ok(PayoutReceipt {
+            block_hash: in_block.block_hash(),
+            success,
+            fee,
+        })
+    }
+
+    // get every slash not yet applied, with the era it will be applied at
+    pub async fn get_unapplied_slashes(&self) -> Option<Vec<(Era, UnappliedSlash)>> {
+        let query = subxt::dynamic::storage("Staking", "UnappliedSlashes", vec![]);
+
+        let mut slashes = vec![];
+        for (key, value) in self.iter_storage(query).await? {
+            let Some(era) = era_from_key(&key) else {
+                continue;
+            };
+            // older runtimes keep all slashes of an era in a single vec
+            if let Ok(era_slashes) = value.as_type::<Vec<UnappliedSlash>>() {
+                slashes.extend(era_slashes.into_iter().map(|slash| (era, slash)));
+            } else if let Ok(slash) = value.as_type::<UnappliedSlash>() {
+                slashes.push((era, slash));
+            }
+        }
+        info!("Fetched {} unapplied slashes", slashes.len());
+        Some(slashes)
+    }
+
+    // get the slashed amount of every validator slashed within the history depth, by era
+    pub async fn get_validator_slashes(&self) -> Option<Vec<(Era, AccountId32, Tokens)>> {
+        let query = subxt::dynamic::storage("Staking", "ValidatorSlashInEra", vec![]);
+
+        let slashes: Vec<(Era, AccountId32, Tokens)> = self
+            .iter_storage(query)
+            .await?
+            .into_iter()
+            .filter_map(|(key, value)| {
+                let (_, amount): (u32, Tokens) = value.as_type().ok()?;
+                Some((era_from_key(&key)?, account_from_key(&key)?, amount))
+            })
+            .collect();
+        info!("Fetched {} validator slashes", slashes.len());
+        Some(slashes)
+    }
+
+    // get the slashing spans of the given account, Some(None) if it was never slashed
+    pub async fn get_slashing_spans(
+        &self,
+        account_id: &AccountId32,
+    ) -> Option<Option<SlashingSpans>> {
+        let query = subxt::dynamic::storage(
+            "Staking",
+            "SlashingSpans",
+            vec![Value::from_bytes(account_id)],
+        );
+
+        let entry = self.query_storage_entry(query).await?;
+        match entry.map(|spans| spans.as_type::<SlashingSpans>()) {
+            Some(Ok(spans)) => {
+                info!(
+                    "Fetched slashing spans for {}",
+                    serialize_address(&self.network, account_id)
+                );
+                Some(Some(spans))
+            }
+            Some(Err(e)) => {
+                warn!("Failed to decode slashing spans: {e}");
+                None
+            }
+            None => Some(None),
+        }
+    }
+
+    // get the ids of every offence report, with the kind of offence reported
+    pub async fn get_offence_reports(&self) -> Option<Vec<(String, H256)>> {
+        if !self.has_pallet("Offences") {
+            return None;
+        }
+        let query = subxt::dynamic::storage("Offences", "ConcurrentReportsIndex", vec![]);
+
+        let mut reports = vec![];
+        for (key, value) in self.iter_storage(query).await? {
+            // the kind is a 16 byte identifier following its twox64 hash
+            let Some(kind) = key.get(40..56) else {
+                continue;
+            };
+            let kind = String::from_utf8_lossy(kind)
+                .trim_end_matches('\0')
+                .to_string();
+            let Ok(ids) = value.as_type::<Vec<H256>>() else {
+                continue;
+            };
+            reports.extend(ids.into_iter().map(|id| (kind.clone(), id)));
+        }
+        info!("Fetched {} offence reports", reports.len());
+        Some(reports)
+    }
+
+    // get the offender of the given offence report
+    pub async fn get_offender(&self, report_id: H256) -> Option<AccountId32> {
+        let query =
+            subxt::dynamic::storage("Offences", "Reports", vec![Value::from_bytes(report_id)]);
+
+        let report = self.query_storage(query).await?.to_value().ok()?;
+        let ValueDef::Composite(fields) = &report.value else {
+            return None;
+        };
+        let offender = fields.values().next().and_then(first_account);
+        offender
+    }
+
+    // get the validators of the current session, in authority index order
+    pub async fn get_session_validators(&self) -> Option<Vec<AccountId32>> {
+        let query = subxt::dynamic::storage("Session", "Validators", ());
+
+        let validators: Option<Vec<AccountId32>> = self.query_decoded(query).await;
+        match validators.as_ref() {
+            Some(v) => info!("Fetched {} session validators", v.len()),
+            None => warn!("Unable to fetch session validators!"),
+        }
+        validators
+    }
+
+    // get the session indices of the validators disabled in the current era
+    pub async fn get_disabled_validators(&self) -> Option<Vec<u32>> {
+        // disabled validators moved from staking to session, along with an offence severity
+        let pallet = ["Session", "Staking"]
+            .into_iter()
+            .find(|pallet| self.has_storage(pallet, "DisabledValidators"))?;
+        let query = subxt::dynamic::storage(pallet, "DisabledValidators", ());
+
+        let disabled = self.query_storage(query).await?.to_value().ok()?;
+        let ValueDef::Composite(disabled) = disabled.value else {
+            warn!("Unable to decode disabled validators!");
+            return None;
+        };
+        let indices: Vec<u32> = disabled
+            .values()
+            .filter_map(|entry| match &entry.value {
+                ValueDef::Composite(fields) => fields.values().next()?.as_u128(),
+                _ => entry.as_u128(),
+            })
+            .filter_map(|index| u32::try_from(index).ok())
+            .collect();
+        info!("Fetched {} disabled validators", indices.len());
+        Some(indices)
+    }
+
+    // get the current session index
+    pub async fn get_session_index(&self) -> Option<u32> {
+        let query = subxt::dynamic::storage("Session", "CurrentIndex", ());
+
+        self.query_decoded(query).await
+    }
+
+    // get the session keys most recently set by the given account
+    pub async fn get_next_keys(&self, account_id: &AccountId32) -> Option<SessionKeys> {
+        let query =
+            subxt::dynamic::storage("Session", "NextKeys", vec![Value::from_bytes(account_id)]);
+
+        let keys = self.query_storage(query).await?.to_value().ok()?;
+        let keys = session_keys(&keys);
+        match keys.as_ref() {
+            Some(_) => info!(
+                "Fetched next session keys for {}",
+                serialize_address(&self.network, account_id)
+            ),
+            None => warn!(
+                "Unable to decode next session keys for {}!",
+                serialize_address(&self.network, account_id)
+            ),
+        }
+        keys
+    }
+
+    // get the scale encoded session keys most recently set by the given account,
+    // Some(None) if it never set any
+    pub async fn get_encoded_next_keys(&self, account_id: &AccountId32) -> Option<Option<Vec<u8>>> {
+        let query =
+            subxt::dynamic::storage("Session", "NextKeys", vec![Value::from_bytes(account_id)]);
+
+        let keys = self.query_storage_entry(query).await?;
+        Some(keys.map(|keys| keys.encoded().to_vec()))
+    }
+
+    // get the session keys of the validators queued for the next session
+    pub async fn get_queued_keys(&self) -> Option<Vec<(AccountId32, SessionKeys)>> {
+        let query = subxt::dynamic::storage("Session", "QueuedKeys", ());
+
+        let queued = self.query_storage(query).await?.to_value().ok()?;
+        let ValueDef::Composite(queued) = queued.value else {
+            warn!("Unable to decode queued session keys!");
+            return None;
+        };
+        let queued: Vec<(AccountId32, SessionKeys)> = queued
+            .values()
+            .filter_map(|entry| {
+                let ValueDef::Composite(entry) = &entry.value else {
+                    return None;
+                };
+                let mut fields = entry.values();
+                Some((
+                    first_account(fields.next()?)?,
+                    session_keys(fields.next()?)?,
+                ))
+            })
+            .collect();
+        info!("Fetched {} queued session keys", queued.len());
+        Some(queued)
+    }
+
+    // get the babe authority keys of the current epoch, in authority index order
+    pub async fn get_babe_authorities(&self) -> Option<Vec<Vec<u8>>> {
+        let query = subxt::dynamic::storage("Babe", "Authorities", ());
+
+        let authorities = self.query_storage(query).await?.to_value().ok()?;
+        let ValueDef::Composite(authorities) = authorities.value else {
+            warn!("Unable to decode babe authorities!");
+            return None;
+        };
+        let authorities: Option<Vec<Vec<u8>>> = authorities.values().map(first_bytes).collect();
+        info!(
+            "Fetched {} babe authorities",
+            authorities.as_ref().map_or(0, |a| a.len())
+        );
+        authorities
+    }
+
+    // get the grandpa authority keys of the current set, in session validator order
+    pub async fn get_grandpa_authorities(&self) -> Option<Vec<Vec<u8>>> {
+        let query = subxt::dynamic::storage("Grandpa", "Authorities", ());
+
+        let authorities = self.query_storage(query).await?.to_value().ok()?;
+        let ValueDef::Composite(authorities) = authorities.value else {
+            warn!("Unable to decode grandpa authorities!");
+            return None;
+        };
+        let authorities: Option<Vec<Vec<u8>>> = authorities.values().map(first_bytes).collect();
+        info!(
+            "Fetched {} grandpa authorities",
+            authorities.as_ref().map_or(0, |a| a.len())
+        );
+        authorities
+    }
+
+    // get the current babe epoch index
+    pub async fn get_epoch_index(&self) -> Option<u64> {
+        let query = subxt::dynamic::storage("Babe", "EpochIndex", ());
+
+        self.query_decoded(query).await
+    }
+
+    // get the current babe epoch, slots are counted from the genesis slot in whole epochs
+    pub async fn get_babe_epoch(&self) -> Option<BabeEpoch> {
+        let index = self.get_epoch_index().await?;
+        let genesis_slot: Slot = self
+            .query_decoded(subxt::dynamic::storage("Babe", "GenesisSlot", ()))
+            .await?;
+        let randomness: [u8; 32] = self
+            .query_decoded(subxt::dynamic::storage("Babe", "Randomness", ()))
+            .await?;
+        let duration: u64 =
+            self.query_constant(subxt::dynamic::constant("Babe", "EpochDuration"))?;
+        let authorities = self.get_babe_authorities().await?.len();
+
+        let epoch = BabeEpoch {
+            index,
+            start_slot: genesis_slot.0 + index * duration,
+            duration,
+            randomness,
+            authorities,
+        };
+        info!(
+            "Fetched babe epoch {index} starting at slot {}",
+            epoch.start_slot
+        );
+        Some(epoch)
+    }
+
+    // get the hash of the block with the given number
+    pub async fn get_block_hash(&self, number: u32) -> Option<H256> {
+        match self
+            .rpc
+            .chain_get_block_hash(Some(NumberOrHex::Number(number.into())))
+            .await
+        {
+            Ok(hash) => hash,
+            Err(e) => {
+                warn!("Unable to fetch hash of block {number}: {e}");
+                None
+            }
+        }
+    }
+
+    // get the latest finalized block number
+    pub async fn get_finalized_block_number(&self) -> Option<u32> {
+        let header = match self.rpc.chain_get_finalized_head().await {
+            Ok(hash) => self.rpc.chain_get_header(Some(hash)).await,
+            Err(e) => Err(e),
+        };
+
+        match header {
+            Ok(header) => header.map(|h| h.number),
+            Err(e) => {
+                warn!("Unable to fetch finalized block: {e}");
+                None
+            }
+        }
+    }
+
+    // get the number of the latest best block
+    pub async fn get_best_block_number(&self) -> Option<u32> {
+        match self.rpc.chain_get_header(None).await {
+            Ok(header) => header.map(|h| h.number),
+            Err(e) => {
+                warn!("Unable to fetch best block: {e}");
+                None
+            }
+        }
+    }
+
+    // get the chain name reported by the node
+    pub async fn get_chain_name(&self) -> Option<String> {
+        match self.rpc.system_chain().await {
+            Ok(chain) => Some(chain),
+            Err(e) => {
+                warn!("Unable to fetch chain name: {e}");
+                None
+            }
+        }
+    }
+
+    // get the babe slot claim of the block with the given hash
+    pub async fn get_babe_claim(&self, hash: H256) -> Option<BabeClaim> {
+        let header = match self.rpc.chain_get_header(Some(hash)).await {
+            Ok(header) => header?,
+            Err(e) => {
+                warn!("Unable to fetch header of block {hash:?}: {e}");
+                return None;
+            }
+        };
+
+        header.digest.logs.iter().find_map(|log| match log {
+            DigestItem::PreRuntime(BABE_ENGINE_ID, data) => BabeClaim::decode(data),
+            _ => None,
+        })
+    }
+
+    // get the events emitted in the block with the given hash
+    pub async fn get_events(&self, hash: H256) -> Option<Events<PolkadotConfig>> {
+        match self.client.events().at(hash).await {
+            Ok(events) => Some(events),
+            Err(e) => {
+                warn!("Unable to fetch events of block {hash:?}: {e}");
+                None
+            }
+        }
+    }
+
+    // get the timestamp (in milliseconds) of the block storage is queried at
+    pub async fn get_timestamp(&self) -> Option<u64> {
+        let query = subxt::dynamic::storage("Timestamp", "Now", ());
+
+        self.query_decoded(query).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn claim_data(variant: u8, authority_index: u32, slot: u64) -> Vec<u8> {
+        let mut data = vec![variant];
+        data.extend(authority_index.to_le_bytes());
+        data.extend(slot.to_le_bytes());
+        data
+    }
+
+    #[test]
+    fn decodes_babe_claim_variants() {
+        let mut primary = claim_data(1, 7, 285_000_000);
+        // the vrf signature following the slot is ignored
+        primary.extend([0; 96]);
+        assert_eq!(
+            BabeClaim::decode(&primary),
+            Some(BabeClaim {
+                primary: true,
+                authority_index: 7,
+                slot: 285_000_000,
+            })
+        );
+        for variant in [2, 3] {
+            assert_eq!(
+                BabeClaim::decode(&claim_data(variant, 296, u64::MAX)),
+                Some(BabeClaim {
+                    primary: false,
+                    authority_index: 296,
+                    slot: u64::MAX,
+                })
+            );
+        }
+    }
+
+    #[test]
+    fn decodes_little_endian_index_and_slot() {
+        let data = [
+            2, 0x01, 0x02, 0x00, 0x00, 0x10, 0x32, 0x54, 0x76, 0, 0, 0, 0,
+        ];
+        assert_eq!(
+            BabeClaim::decode(&data),
+            Some(BabeClaim {
+                primary: false,
+                authority_index: 0x0201,
+                slot: 0x7654_3210,
+            })
+        );
+    }
+
+    #[test]
+    fn rejects_unknown_variants_and_short_claims() {
+        assert_eq!(BabeClaim::decode(&claim_data(0, 1, 1)), None);
+        assert_eq!(BabeClaim::decode(&claim_data(4, 1, 1)), None);
+        assert_eq!(BabeClaim::decode(&claim_data(1, 1, 1)[..12]), None);
+        assert_eq!(BabeClaim::decode(&[]), None);
+    }
 }
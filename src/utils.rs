@@ -1,3 +1,4 @@
+use crate::substrate::Tokens;
 use crate::{constants, constants::Network};
 
 use log::info;
@@ -6,7 +7,7 @@ use std::fs;
 use base58::ToBase58;
 use blake2::{Blake2b512, Digest};
 
-use clap::Parser;
+use clap::{Parser, Subcommand};
 use serde::Deserialize;
 use subxt::utils::AccountId32;
 
@@ -22,6 +23,41 @@ pub struct Args {
 
     #[arg(long, default_value_t = 8000)]
     pub port: u32,
+
+    #[command(subcommand)]
+    pub command: Option<Command>,
+}
+
+// subcommand format
+#[derive(Subcommand, Debug, Clone)]
+pub enum Command {
+    /// Write historical era metrics to an OpenMetrics file for promtool import
+    Backfill {
+        #[arg(long)]
+        from_era: u32,
+
+        #[arg(long)]
+        to_era: u32,
+
+        /// Archive node to query, defaults to the configured rpc_url
+        #[arg(long)]
+        archive_url: Option<String>,
+
+        #[arg(short, long, default_value_t = String::from("backfill.om"))]
+        output: String,
+    },
+    /// Write per-era validator reports in CSV and Markdown
+    Report {
+        #[arg(long)]
+        from_era: u32,
+
+        #[arg(long)]
+        to_era: u32,
+
+        /// Directory to write to, defaults to the configured report_dir
+        #[arg(short, long)]
+        output: Option<String>,
+    },
 }
 
 // config parser format
@@ -29,6 +65,14 @@ pub struct Args {
 pub struct Validator {
     pub name: String,
     pub address: String,
+    pub node_rpc_url: Option<String>,
+}
+#[derive(Deserialize, Debug, Clone)]
+pub struct PayoutConfig {
+    pub keystore: String,
+    #[serde(default)]
+    pub dry_run: bool,
+    pub batch_size: Option<usize>,
 }
 #[derive(Deserialize, Debug, Clone)]
 pub struct Config {
@@ -36,7 +80,13 @@ pub struct Config {
     pub chain: String,
     pub rpc_url: String,
     pub backup_rpc_url: String,
+    pub relay_rpc_url: Option<String>,
     pub validators: Vec<Validator>,
+    pub history_eras: Option<u32>,
+    pub store_path: Option<String>,
+    pub report_dir: Option<String>,
+    pub payouts: Option<PayoutConfig>,
+    pub grandpa_rpc_url: Option<String>,
 }
 impl Config {
     // load config from file
@@ -67,3 +117,37 @@ pub fn serialize_address(network: &Network, account_id: &AccountId32) -> String
     v.extend(&r[0..2]);
     v.to_base58()
 }
+
+// number of decimals of the network's token
+fn decimals(network: &Network) -> u32 {
+    match network {
+        Network::Polkadot => constants::POLKADOT_DECIMALS,
+        Network::Kusama => constants::KUSAMA_DECIMALS,
+        Network::Avail => constants::AVAIL_DECIMALS,
+    }
+}
+
+// convert an amount in the smallest unit to whole tokens
+pub fn tokens_to_units(network: &Network, amount: Tokens) -> f64 {
+    amount as f64 / 10_f64.powi(decimals(network) as i32)
+}
+
+// format an amount in the smallest unit as whole tokens
+pub fn format_tokens(network: &Network, amount: Tokens) -> String {
+    let decimals = decimals(network);
+
+    let unit = 10_i128.pow(decimals);
+    let sign = if amount < 0 { "-" } else { "" };
+    let amount = amount.unsigned_abs();
+    format!(
+        "{sign}{}.{:04}",
+        amount / unit as u128,
+        amount % unit as u128 / 10_u128.pow(decimals - 4)
+    )
+}
+
+// format bytes as a 0x-prefixed hex string
+pub fn format_hex(bytes: &[u8]) -> String {
+    let hex: String = bytes.iter().map(|b| format!("{b:02x}")).collect();
+    format!("0x{hex}")
+}
@@ -1,92 +1,2155 @@
+use crate::constants::{DEFAULT_HISTORY_ERAS, DEFAULT_PAYOUT_BATCH_SIZE};
+use crate::helper::{chain_labels, relay_rpc, validator_labels};
+use crate::history;
 use crate::http::State;
-use crate::prometheus::Labels;
-use crate::substrate::{tokens_to_i64, EraPointsMap};
-use crate::utils::Validator;
+use crate::node::{NodeRPC, RoundVotes};
+use crate::payouts::{self, Payer, PayoutCall};
+use crate::phragmen;
+use crate::prometheus::{
+    BackingLabels, EraLabels, KeyLabels, KindLabels, Labels, PageLabels, PhaseLabels, RewardLabels,
+    VersionLabels,
+};
+use crate::report;
+use crate::rewards;
+use crate::slots;
+use crate::stats;
+use crate::store::{RewardEntry, SlashingEntry};
+use crate::substrate::{
+    tokens_to_i64, Era, EraPointsMap, PayoutStarted, Rewarded, SubstrateRPC, Tokens,
+    UnappliedSlash, UnclaimedPayouts,
+};
+use crate::utils::{format_hex, serialize_address, tokens_to_units, Validator};
 
 use async_std::task;
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
 use std::sync::Arc;
 use std::time::Duration;
-use subxt::utils::AccountId32;
+use subxt::events::{Events, Phase};
+use subxt::utils::{AccountId32, H256};
+use subxt::PolkadotConfig;
+use subxt_signer::sr25519::Keypair;
 
 const SCRAPE_INTERVAL: u64 = 1;
 
+// interval between slashing storage scans, in seconds
+const SLASHING_INTERVAL: u64 = 60;
+
+// interval between validator node checks, in seconds
+const NODE_INTERVAL: u64 = 10;
+
+// window over which era point rates are measured, in milliseconds
+const RATE_WINDOW: u64 = 3_600_000;
+
 async fn _wait_for_rpc(state: &State) -> Arc<crate::substrate::SubstrateRPC> {
     loop {
         if let Some(rpc) = state.rpc.read().await.clone() {
             return rpc;
         }
 
-        task::sleep(Duration::from_secs(2)).await;
+        task::sleep(Duration::from_secs(2)).await;
+    }
+}
+
+// Chain-level metrics worker
+pub async fn chain_metrics_worker(state: State) {
+    let labels = Labels {
+        network: serde_yaml::to_string(&state.config.network)
+            .unwrap()
+            .trim()
+            .into(),
+        chain: state.config.chain.clone(),
+        validator_name: None,
+        validator_address: None,
+    };
+    // election phase published in the previous iteration
+    let mut published_phase: Option<String> = None;
+
+    // let rpc = wait_for_rpc(&state).await;
+    loop {
+        if *state.shutdown.read().await {
+            log::info!("chain_metrics_worker shutting down");
+            break;
+        }
+        let rpc_opt = state.rpc.read().await.clone();
+        match rpc_opt {
+            Some(rpc) => {
+                // median era points of the active set along with their era
+                let mut median_points = None;
+
+                if let Some(mut era) = rpc.get_current_era().await {
+                    state.metrics.era.get_or_create(&labels).set(era.into());
+                    if let Some(planned_era) = rpc.get_planned_era().await {
+                        state
+                            .metrics
+                            .planned_era
+                            .get_or_create(&labels)
+                            .set(planned_era.into());
+                    }
+                    let era_points = match rpc.get_all_era_points(era).await {
+                        Some(res) => res,
+                        // if fetching from current era fails, try fetching from previous era
+                        None => match rpc.get_all_era_points(era - 1).await {
+                            Some(res) => {
+                                era -= 1;
+                                res
+                            }
+                            None => EraPointsMap::default(),
+                        },
+                    };
+
+                    let active_count = era_points.individual.len() as i64;
+
+                    // publish era points distribution of the active set
+                    let mut all_points: Vec<f64> = era_points
+                        .individual
+                        .iter()
+                        .map(|(_, p)| *p as f64)
+                        .collect();
+                    all_points.sort_by(f64::total_cmp);
+                    for (metric, value) in [
+                        (
+                            &state.metrics.era_points_min,
+                            stats::percentile(&all_points, 0.0),
+                        ),
+                        (
+                            &state.metrics.era_points_p10,
+                            stats::percentile(&all_points, 10.0),
+                        ),
+                        (&state.metrics.era_points_median, stats::median(&all_points)),
+                        (
+                            &state.metrics.era_points_p90,
+                            stats::percentile(&all_points, 90.0),
+                        ),
+                        (
+                            &state.metrics.era_points_max,
+                            stats::percentile(&all_points, 100.0),
+                        ),
+                    ] {
+                        metric.get_or_create(&labels).set(value);
+                    }
+                    median_points = Some((era, stats::median(&all_points)));
+
+                    if let Some(min) = rpc.get_minimum_active_stake().await {
+                        state
+                            .metrics
+                            .minimum_active_stake
+                            .get_or_create(&labels)
+                            .set(tokens_to_i64(min));
+                    }
+
+                    if let Some(total) = rpc.get_total_stake(era).await {
+                        if active_count > 0 {
+                            state
+                                .metrics
+                                .average_stake
+                                .get_or_create(&labels)
+                                .set(tokens_to_i64(total) / active_count);
+                        }
+                    }
+                }
+
+                // publish era progress metrics
+                if let Some(progress) = rpc
+                    .get_era_progress(relay_rpc(&state).await.as_deref())
+                    .await
+                {
+                    state
+                        .metrics
+                        .era_start_timestamp
+                        .get_or_create(&labels)
+                        .set((progress.start / 1000) as i64);
+                    state
+                        .metrics
+                        .era_elapsed_seconds
+                        .get_or_create(&labels)
+                        .set((progress.elapsed() / 1000) as i64);
+                    if let Some(remaining) = progress.remaining {
+                        state
+                            .metrics
+                            .era_remaining_seconds
+                            .get_or_create(&labels)
+                            .set((remaining / 1000) as i64);
+                        state.metrics.era_progress.get_or_create(&labels).set(
+                            progress.elapsed() as f64 / (progress.elapsed() + remaining) as f64,
+                        );
+                    }
+
+                    // median point rate of the active set since the era started
+                    if let Some((_, median)) = median_points.filter(|(e, _)| *e == progress.era) {
+                        let rate = stats::per_hour(median, progress.elapsed());
+                        state
+                            .metrics
+                            .median_era_points_per_hour
+                            .get_or_create(&labels)
+                            .set(rate);
+                        if let Some(remaining) = progress.remaining {
+                            state
+                                .metrics
+                                .projected_median_era_points
+                                .get_or_create(&labels)
+                                .set(median + rate * stats::hours(remaining));
+                        }
+                    }
+
+                    if let Some(session_index) = progress.session_index {
+                        state
+                            .metrics
+                            .session_index
+                            .get_or_create(&labels)
+                            .set(session_index.into());
+                    }
+                    if let Some(session_in_era) = progress.session_in_era {
+                        state
+                            .metrics
+                            .session_in_era
+                            .get_or_create(&labels)
+                            .set(session_in_era.into());
+                    }
+                }
+
+                // publish election provider metrics
+                let phase = rpc.get_election_phase().await;
+                if phase != published_phase {
+                    if let Some(previous) = published_phase.take() {
+                        state.metrics.election_phase.remove(&PhaseLabels {
+                            phase: previous,
+                            labels: labels.clone(),
+                        });
+                    }
+                    if let Some(current) = phase.clone() {
+                        state
+                            .metrics
+                            .election_phase
+                            .get_or_create(&PhaseLabels {
+                                phase: current,
+                                labels: labels.clone(),
+                            })
+                            .set(1);
+                    }
+                    published_phase = phase;
+                }
+
+                state
+                    .metrics
+                    .election_emergency
+                    .get_or_create(&labels)
+                    .set((published_phase.as_deref() == Some("Emergency")).into());
+
+                // no queued solution scores 0, failed queries drop the series
+                match rpc.get_queued_solution_score().await {
+                    Some(score) => {
+                        let score = score.unwrap_or_default();
+                        state
+                            .metrics
+                            .queued_solution_minimal_stake
+                            .get_or_create(&labels)
+                            .set(tokens_to_i64(score.minimal_stake));
+                        state
+                            .metrics
+                            .queued_solution_sum_stake
+                            .get_or_create(&labels)
+                            .set(tokens_to_i64(score.sum_stake));
+                    }
+                    None => {
+                        state.metrics.queued_solution_minimal_stake.remove(&labels);
+                        state.metrics.queued_solution_sum_stake.remove(&labels);
+                    }
+                }
+
+                match rpc.get_signed_submission_count().await {
+                    Some(submissions) => {
+                        state
+                            .metrics
+                            .signed_submissions
+                            .get_or_create(&labels)
+                            .set(submissions.into());
+                    }
+                    None => {
+                        state.metrics.signed_submissions.remove(&labels);
+                    }
+                }
+            }
+            None => {
+                // RPC DOWN → RESET TO DEFAULTS
+                state.metrics.era.get_or_create(&labels).set(0);
+                state.metrics.planned_era.get_or_create(&labels).set(0);
+                for metric in [
+                    &state.metrics.era_points_min,
+                    &state.metrics.era_points_p10,
+                    &state.metrics.era_points_median,
+                    &state.metrics.era_points_p90,
+                    &state.metrics.era_points_max,
+                ] {
+                    metric.get_or_create(&labels).set(0.0);
+                }
+                state
+                    .metrics
+                    .minimum_active_stake
+                    .get_or_create(&labels)
+                    .set(0);
+                state.metrics.average_stake.get_or_create(&labels).set(0);
+                if let Some(previous) = published_phase.take() {
+                    state.metrics.election_phase.remove(&PhaseLabels {
+                        phase: previous,
+                        labels: labels.clone(),
+                    });
+                }
+                state
+                    .metrics
+                    .election_emergency
+                    .get_or_create(&labels)
+                    .set(0);
+                for metric in [
+                    &state.metrics.queued_solution_minimal_stake,
+                    &state.metrics.queued_solution_sum_stake,
+                    &state.metrics.signed_submissions,
+                ] {
+                    metric.remove(&labels);
+                }
+            }
+        }
+
+        task::sleep(Duration::from_secs(SCRAPE_INTERVAL)).await;
+    }
+}
+
+// Nomination index worker, rebuilds the index of all nominations once per era
+pub async fn nomination_index_worker(state: State) {
+    loop {
+        if *state.shutdown.read().await {
+            log::info!("nomination_index_worker shutting down");
+            break;
+        }
+        let rpc_opt = state.rpc.read().await.clone();
+        if let Some(rpc) = rpc_opt {
+            if let Some(era) = rpc.get_current_era().await {
+                let indexed_era = state.nominations.read().await.as_ref().map(|(e, _)| *e);
+                if indexed_era != Some(era) {
+                    if let Some(index) = rpc.get_nomination_index().await {
+                        *state.nominations.write().await = Some((era, index));
+                    }
+                }
+            }
+        }
+
+        task::sleep(Duration::from_secs(SCRAPE_INTERVAL)).await;
+    }
+}
+
+// Election margin worker, ranks the tracked validators against the active set once per era
+pub async fn election_margin_worker(state: State) {
+    let labels = chain_labels(&state.config);
+    let mut ranked_era = None;
+
+    loop {
+        if *state.shutdown.read().await {
+            log::info!("election_margin_worker shutting down");
+            break;
+        }
+        let rpc_opt = state.rpc.read().await.clone();
+        let Some(rpc) = rpc_opt else {
+            // RPC DOWN → RESET TO DEFAULTS
+            state.metrics.active_set_size.get_or_create(&labels).set(0);
+            state
+                .metrics
+                .lowest_elected_backing
+                .get_or_create(&labels)
+                .set(0);
+            for validator in state.config.validators.iter() {
+                let validator_labels = validator_labels(&state.config, validator);
+                state
+                    .metrics
+                    .rank_by_backing
+                    .get_or_create(&validator_labels)
+                    .set(0);
+                state
+                    .metrics
+                    .backing_margin
+                    .get_or_create(&validator_labels)
+                    .set(0);
+            }
+            ranked_era = None;
+
+            task::sleep(Duration::from_secs(SCRAPE_INTERVAL)).await;
+            continue;
+        };
+
+        let era = rpc.get_current_era().await;
+        if era.is_none() || era == ranked_era {
+            task::sleep(Duration::from_secs(SCRAPE_INTERVAL)).await;
+            continue;
+        }
+        let era = era.unwrap();
+
+        // exposures of the active era may not be stored yet, fall back to the previous era
+        // and retry the active era until they are
+        let summaries = match rpc.get_all_nominator_summaries(era).await {
+            Some(res) => Some((era, res)),
+            None => rpc
+                .get_all_nominator_summaries(era.saturating_sub(1))
+                .await
+                .map(|res| (era.saturating_sub(1), res)),
+        };
+
+        if let Some((summaries_era, mut summaries)) = summaries {
+            summaries.sort_by_key(|(_, s)| std::cmp::Reverse(s.total));
+            let lowest = summaries.last().map(|(_, s)| s.total).unwrap_or_default();
+
+            state
+                .metrics
+                .active_set_size
+                .get_or_create(&labels)
+                .set(summaries.len() as i64);
+            state
+                .metrics
+                .lowest_elected_backing
+                .get_or_create(&labels)
+                .set(tokens_to_i64(lowest));
+
+            for validator in state.config.validators.iter() {
+                let validator_labels = validator_labels(&state.config, validator);
+                let Ok(account_id) = validator.address.parse::<AccountId32>() else {
+                    continue;
+                };
+
+                let (rank, margin) = summaries
+                    .iter()
+                    .position(|(id, _)| id == &account_id)
+                    .map(|i| (i as i64 + 1, summaries[i].1.total - lowest))
+                    .unwrap_or_default();
+
+                state
+                    .metrics
+                    .rank_by_backing
+                    .get_or_create(&validator_labels)
+                    .set(rank);
+                state
+                    .metrics
+                    .backing_margin
+                    .get_or_create(&validator_labels)
+                    .set(tokens_to_i64(margin));
+            }
+
+            if summaries_era == era {
+                ranked_era = Some(era);
+            }
+        }
+
+        task::sleep(Duration::from_secs(SCRAPE_INTERVAL)).await;
+    }
+}
+
+// History worker, backfills the results of the last completed eras at startup and on each new era
+pub async fn history_worker(state: State) {
+    let labels = chain_labels(&state.config);
+    let mut history_era = None;
+
+    loop {
+        if *state.shutdown.read().await {
+            log::info!("history_worker shutting down");
+            break;
+        }
+        let rpc_opt = state.rpc.read().await.clone();
+        let Some(rpc) = rpc_opt else {
+            // RPC DOWN → RESET TO DEFAULTS
+            state.metrics.history_eras.get_or_create(&labels).set(0);
+            for validator in state.config.validators.iter() {
+                let validator_labels = validator_labels(&state.config, validator);
+                state
+                    .metrics
+                    .history_eras_active
+                    .get_or_create(&validator_labels)
+                    .set(0);
+                state
+                    .metrics
+                    .history_average_era_points
+                    .get_or_create(&validator_labels)
+                    .set(0.0);
+                state
+                    .metrics
+                    .history_average_backing
+                    .get_or_create(&validator_labels)
+                    .set(0);
+            }
+            history_era = None;
+
+            task::sleep(Duration::from_secs(SCRAPE_INTERVAL)).await;
+            continue;
+        };
+
+        let era = rpc.get_current_era().await;
+        if era.is_none() || era == history_era {
+            task::sleep(Duration::from_secs(SCRAPE_INTERVAL)).await;
+            continue;
+        }
+        let era = era.unwrap();
+
+        let depth = rpc.get_history_depth().unwrap_or(DEFAULT_HISTORY_ERAS);
+        let eras = state
+            .config
+            .history_eras
+            .unwrap_or(DEFAULT_HISTORY_ERAS)
+            .min(depth);
+        let range = era.saturating_sub(eras)..era;
+
+        // backfill completed eras missing from history
+        let mut complete = true;
+        for past_era in range.clone() {
+            if state.history.read().await.contains_key(&past_era) {
+                continue;
+            }
+            match history::fetch_era_record(&rpc, past_era, &state.config.validators).await {
+                Some(record) => {
+                    if let Some(store) = state.store.as_ref() {
+                        if let Err(e) = store.insert_era(&record) {
+                            log::warn!("Unable to store results of era {past_era}: {e}");
+                        }
+                    }
+                    if let Some(dir) = state.config.report_dir.as_ref() {
+                        let name = format!("era-{past_era}");
+                        let records = std::slice::from_ref(&record);
+                        if let Err(e) = report::write_report(&state.config, dir, &name, records) {
+                            log::warn!("Unable to write report of era {past_era}: {e}");
+                        }
+                    }
+                    state.history.write().await.insert(past_era, record);
+                }
+                None => complete = false,
+            }
+        }
+        state
+            .history
+            .write()
+            .await
+            .retain(|past_era, _| range.contains(past_era));
+
+        // publish rolling history metrics
+        let history = state.history.read().await;
+        state
+            .metrics
+            .history_eras
+            .get_or_create(&labels)
+            .set(history.len() as i64);
+
+        for validator in state.config.validators.iter() {
+            let validator_labels = validator_labels(&state.config, validator);
+            let active: Vec<_> = history
+                .values()
+                .filter_map(|record| record.validators.get(&validator.address))
+                .filter(|record| record.active)
+                .collect();
+
+            let average_points =
+                stats::mean(&active.iter().map(|r| r.points as f64).collect::<Vec<_>>());
+            let average_backing = match active.len() {
+                0 => 0,
+                n => active.iter().map(|r| r.backing).sum::<Tokens>() / n as Tokens,
+            };
+
+            state
+                .metrics
+                .history_eras_active
+                .get_or_create(&validator_labels)
+                .set(active.len() as i64);
+            state
+                .metrics
+                .history_average_era_points
+                .get_or_create(&validator_labels)
+                .set(average_points);
+            state
+                .metrics
+                .history_average_backing
+                .get_or_create(&validator_labels)
+                .set(tokens_to_i64(average_backing));
+        }
+        drop(history);
+
+        if complete {
+            history_era = Some(era);
+        }
+
+        task::sleep(Duration::from_secs(SCRAPE_INTERVAL)).await;
+    }
+}
+
+// Reward worker, estimates payouts and returns of the last completed era once per era
+pub async fn reward_worker(state: State) {
+    let labels = chain_labels(&state.config);
+    let mut estimated_era = None;
+    let mut estimates = BTreeMap::new();
+    // start of the last seen era, to measure the era duration on chains without babe
+    let mut last_start: Option<(Era, u64)> = None;
+    let mut observed_duration = None;
+
+    loop {
+        if *state.shutdown.read().await {
+            log::info!("reward_worker shutting down");
+            break;
+        }
+        let rpc_opt = state.rpc.read().await.clone();
+        let Some(rpc) = rpc_opt else {
+            // RPC DOWN → RESET TO DEFAULTS
+            state.metrics.average_apy.remove(&labels);
+            for validator in state.config.validators.iter() {
+                let validator_labels = validator_labels(&state.config, validator);
+                for metric in [
+                    &state.metrics.estimated_payout,
+                    &state.metrics.commission_payout,
+                    &state.metrics.nominator_payout,
+                ] {
+                    metric.get_or_create(&validator_labels).set(0);
+                }
+                state.metrics.apy.remove(&validator_labels);
+            }
+            estimated_era = None;
+
+            task::sleep(Duration::from_secs(SCRAPE_INTERVAL)).await;
+            continue;
+        };
+
+        let Some(progress) = rpc
+            .get_era_progress(relay_rpc(&state).await.as_deref())
+            .await
+        else {
+            task::sleep(Duration::from_secs(SCRAPE_INTERVAL)).await;
+            continue;
+        };
+        if let Some((era, start)) = last_start {
+            if progress.era == era + 1 {
+                observed_duration = Some(progress.start.saturating_sub(start));
+            }
+        }
+        last_start = Some((progress.era, progress.start));
+
+        // payouts are only known once an era completes
+        let completed_era = progress.era.checked_sub(1);
+        if let Some(completed_era) = completed_era.filter(|era| Some(*era) != estimated_era) {
+            let (Some(reward), Some(points), Some(summaries), Some(commissions)) = (
+                rpc.get_validator_reward(completed_era).await,
+                rpc.get_all_era_points(completed_era).await,
+                rpc.get_all_nominator_summaries(completed_era).await,
+                rpc.get_all_validator_commissions(completed_era).await,
+            ) else {
+                task::sleep(Duration::from_secs(SCRAPE_INTERVAL)).await;
+                continue;
+            };
+            estimates = rewards::estimate_payouts(reward, &points, &summaries, &commissions);
+            estimated_era = Some(completed_era);
+        }
+
+        // annualized returns are only published once the era duration is known
+        let era_duration = progress.era_duration.or(observed_duration).unwrap_or(0);
+        match rewards::annualize(rewards::average_rate(&estimates), era_duration) {
+            Some(apy) => {
+                state.metrics.average_apy.get_or_create(&labels).set(apy);
+            }
+            None => {
+                state.metrics.average_apy.remove(&labels);
+            }
+        }
+
+        for validator in state.config.validators.iter() {
+            let validator_labels = validator_labels(&state.config, validator);
+            let estimate = validator
+                .address
+                .parse::<AccountId32>()
+                .ok()
+                .and_then(|account_id| estimates.get(&account_id))
+                .cloned()
+                .unwrap_or_default();
+
+            state
+                .metrics
+                .estimated_payout
+                .get_or_create(&validator_labels)
+                .set(tokens_to_i64(estimate.payout));
+            state
+                .metrics
+                .commission_payout
+                .get_or_create(&validator_labels)
+                .set(tokens_to_i64(estimate.commission));
+            state
+                .metrics
+                .nominator_payout
+                .get_or_create(&validator_labels)
+                .set(tokens_to_i64(estimate.nominators));
+            match rewards::annualize(estimate.rate, era_duration) {
+                Some(apy) => {
+                    state.metrics.apy.get_or_create(&validator_labels).set(apy);
+                }
+                None => {
+                    state.metrics.apy.remove(&validator_labels);
+                }
+            }
+        }
+
+        task::sleep(Duration::from_secs(SCRAPE_INTERVAL)).await;
+    }
+}
+
+// Unclaimed payouts worker, tracks exposure pages not yet paid out within the history depth
+pub async fn unclaimed_payouts_worker(state: State) {
+    // number of pages to claim per validator address and completed era
+    let mut payable: BTreeMap<(String, Era), u32> = BTreeMap::new();
+    let mut claimed: BTreeSet<(String, Era)> = BTreeSet::new();
+    let mut published: BTreeMap<String, BTreeSet<Era>> = BTreeMap::new();
+
+    loop {
+        if *state.shutdown.read().await {
+            log::info!("unclaimed_payouts_worker shutting down");
+            break;
+        }
+        let rpc_opt = state.rpc.read().await.clone();
+        let Some(rpc) = rpc_opt else {
+            // RPC DOWN → RESET TO DEFAULTS
+            for validator in state.config.validators.iter() {
+                let labels = validator_labels(&state.config, validator);
+                state.metrics.unclaimed_eras.get_or_create(&labels).set(0);
+                state.metrics.oldest_unclaimed_era.remove(&labels);
+                state.metrics.eras_until_expiry.remove(&labels);
+                for era in published.remove(&validator.address).unwrap_or_default() {
+                    state.metrics.unclaimed_pages.remove(&EraLabels {
+                        era,
+                        labels: labels.clone(),
+                    });
+                }
+            }
+            state.unclaimed.write().await.clear();
+
+            task::sleep(Duration::from_secs(SCRAPE_INTERVAL)).await;
+            continue;
+        };
+
+        let (Some(active_era), Some(planned_era), Some(depth)) = (
+            rpc.get_current_era().await,
+            rpc.get_planned_era().await,
+            rpc.get_history_depth(),
+        ) else {
+            task::sleep(Duration::from_secs(SCRAPE_INTERVAL)).await;
+            continue;
+        };
+
+        // payouts of eras older than the history depth can no longer be claimed
+        let range = planned_era.saturating_sub(depth)..active_era;
+        payable.retain(|(_, era), _| range.contains(era));
+        claimed.retain(|(_, era)| range.contains(era));
+
+        // pages to claim are fixed once an era completes
+        for era in range.clone() {
+            let known = state
+                .config
+                .validators
+                .iter()
+                .all(|v| payable.contains_key(&(v.address.clone(), era)));
+            if known {
+                continue;
+            }
+            let Some(points) = rpc.get_all_era_points(era).await else {
+                continue;
+            };
+            for validator in state.config.validators.iter() {
+                let Ok(account_id) = validator.address.parse::<AccountId32>() else {
+                    continue;
+                };
+                // a validator without points has nothing to claim
+                let earned = points
+                    .individual
+                    .iter()
+                    .any(|(id, p)| id == &account_id && *p > 0);
+                let pages = match earned {
+                    true => match rpc.get_nominator_summary(era, &account_id).await {
+                        Some(summary) => summary.page_count.max(1),
+                        None => continue,
+                    },
+                    false => 0,
+                };
+                payable.insert((validator.address.clone(), era), pages);
+            }
+        }
+
+        let mut unclaimed = UnclaimedPayouts::new();
+        for validator in state.config.validators.iter() {
+            let labels = validator_labels(&state.config, validator);
+            let Ok(account_id) = validator.address.parse::<AccountId32>() else {
+                continue;
+            };
+
+            let mut eras = BTreeMap::new();
+            for era in range.clone() {
+                let key = (validator.address.clone(), era);
+                let pages = payable.get(&key).copied().unwrap_or_default();
+                if pages == 0 || claimed.contains(&key) {
+                    continue;
+                }
+                let Some(claimed_pages) = rpc.get_claimed_pages(era, &account_id).await else {
+                    continue;
+                };
+                let pages: Vec<u32> = (0..pages)
+                    .filter(|page| !claimed_pages.contains(page))
+                    .collect();
+                if pages.is_empty() {
+                    claimed.insert(key);
+                } else {
+                    eras.insert(era, pages);
+                }
+            }
+
+            state
+                .metrics
+                .unclaimed_eras
+                .get_or_create(&labels)
+                .set(eras.len() as i64);
+            match eras.keys().next() {
+                Some(oldest) => {
+                    state
+                        .metrics
+                        .oldest_unclaimed_era
+                        .get_or_create(&labels)
+                        .set((*oldest).into());
+                    state
+                        .metrics
+                        .eras_until_expiry
+                        .get_or_create(&labels)
+                        .set(i64::from(oldest + depth + 1) - i64::from(planned_era));
+                }
+                None => {
+                    state.metrics.oldest_unclaimed_era.remove(&labels);
+                    state.metrics.eras_until_expiry.remove(&labels);
+                }
+            }
+
+            // drop series of eras that were claimed or expired
+            let published_eras = published.entry(validator.address.clone()).or_default();
+            for era in published_eras.iter().filter(|era| !eras.contains_key(era)) {
+                state.metrics.unclaimed_pages.remove(&EraLabels {
+                    era: *era,
+                    labels: labels.clone(),
+                });
+            }
+            for (era, pages) in eras.iter() {
+                state
+                    .metrics
+                    .unclaimed_pages
+                    .get_or_create(&EraLabels {
+                        era: *era,
+                        labels: labels.clone(),
+                    })
+                    .set(pages.len() as i64);
+            }
+            *published_eras = eras.keys().copied().collect();
+
+            unclaimed.insert(validator.address.clone(), eras);
+        }
+        *state.unclaimed.write().await = unclaimed;
+
+        task::sleep(Duration::from_secs(SCRAPE_INTERVAL)).await;
+    }
+}
+
+// Payout worker, pays out unclaimed pages found by the unclaimed payouts worker
+pub async fn payout_worker(state: State, signer: Keypair) {
+    let Some(config) = state.config.payouts.clone() else {
+        return;
+    };
+    let labels = chain_labels(&state.config);
+    let batch_size = config.batch_size.unwrap_or(DEFAULT_PAYOUT_BATCH_SIZE);
+    // failed payouts are retried once per era
+    let mut attempted = BTreeSet::new();
+    let mut attempted_era = None;
+
+    loop {
+        if *state.shutdown.read().await {
+            log::info!("payout_worker shutting down");
+            break;
+        }
+        let rpc_opt = state.rpc.read().await.clone();
+        let Some(rpc) = rpc_opt else {
+            task::sleep(Duration::from_secs(SCRAPE_INTERVAL)).await;
+            continue;
+        };
+
+        let era = rpc.get_current_era().await;
+        if era != attempted_era {
+            attempted.clear();
+            attempted_era = era;
+        }
+
+        let calls = payouts::pending_payouts(&*state.unclaimed.read().await, &attempted);
+        if calls.is_empty() {
+            task::sleep(Duration::from_secs(SCRAPE_INTERVAL)).await;
+            continue;
+        }
+
+        let payer = Payer::new(rpc, signer.clone());
+        let report = payouts::pay_out(&payer, &calls, batch_size, config.dry_run).await;
+        attempted.extend(calls);
+
+        for validator in state.config.validators.iter() {
+            let validator_labels = validator_labels(&state.config, validator);
+            let count = |calls: &[PayoutCall]| {
+                calls
+                    .iter()
+                    .filter(|call| call.validator == validator.address)
+                    .count() as u64
+            };
+            state
+                .metrics
+                .payouts_succeeded
+                .get_or_create(&validator_labels)
+                .inc_by(count(&report.paid));
+            state
+                .metrics
+                .payouts_failed
+                .get_or_create(&validator_labels)
+                .inc_by(count(&report.failed));
+        }
+        state
+            .metrics
+            .payout_fees
+            .get_or_create(&labels)
+            .inc_by(tokens_to_units(&state.config.network, report.fees));
+
+        task::sleep(Duration::from_secs(SCRAPE_INTERVAL)).await;
+    }
+}
+
+// Finalized block worker, processes the events and authors of every block finalized since startup
+pub async fn finalized_block_worker(state: State) {
+    let mut last_block = None;
+    let mut last_relay_block = None;
+    let mut authorship = Authorship::default();
+
+    loop {
+        if *state.shutdown.read().await {
+            log::info!("finalized_block_worker shutting down");
+            break;
+        }
+
+        // rewards are paid out on the staking chain
+        let rpc_opt = state.rpc.read().await.clone();
+        if let Some(rpc) = rpc_opt {
+            if let Some(finalized) = rpc.get_finalized_block_number().await {
+                // catch up on blocks finalized since the last processed block
+                let from = last_block.map_or(finalized, |block: u32| block + 1);
+                for number in from..=finalized {
+                    let Some(hash) = rpc.get_block_hash(number).await else {
+                        break;
+                    };
+                    let Some(events) = rpc.get_events(hash).await else {
+                        break;
+                    };
+                    process_rewards(&state, number, hash, &events).await;
+                    last_block = Some(number);
+                }
+            }
+        }
+
+        // blocks are authored by validators on the relay chain, the series are
+        // dropped while no babe relay chain is reachable
+        match relay_rpc(&state).await {
+            Some(relay) => {
+                if last_relay_block.is_none() {
+                    initialize_authorship(&state);
+                }
+                if let Some(finalized) = relay.get_finalized_block_number().await {
+                    let from = last_relay_block.map_or(finalized, |block: u32| block + 1);
+                    for number in from..=finalized {
+                        let Some(hash) = relay.get_block_hash(number).await else {
+                            break;
+                        };
+                        process_authorship(&state, &relay, hash, &mut authorship).await;
+                        last_relay_block = Some(number);
+                    }
+                }
+            }
+            None => {
+                if last_relay_block.is_some() {
+                    remove_authorship(&state);
+                }
+                last_relay_block = None;
+                authorship = Authorship::default();
+            }
+        }
+
+        task::sleep(Duration::from_secs(SCRAPE_INTERVAL)).await;
+    }
+}
+
+// start the authorship series of tracked validators from zero
+fn initialize_authorship(state: &State) {
+    for validator in state.config.validators.iter() {
+        let validator_labels = validator_labels(&state.config, validator);
+        for metric in [
+            &state.metrics.blocks_authored,
+            &state.metrics.secondary_slots,
+            &state.metrics.secondary_slots_missed,
+        ] {
+            metric.get_or_create(&validator_labels).inc_by(0);
+        }
+        state
+            .metrics
+            .secondary_slot_miss_ratio
+            .get_or_create(&validator_labels)
+            .set(0.0);
+        for metric in [
+            &state.metrics.blocks_authored_session,
+            &state.metrics.blocks_authored_era,
+            &state.metrics.epoch_secondary_slots,
+        ] {
+            metric.get_or_create(&validator_labels).set(0);
+        }
+    }
+}
+
+fn remove_authorship(state: &State) {
+    for validator in state.config.validators.iter() {
+        let validator_labels = validator_labels(&state.config, validator);
+        for metric in [
+            &state.metrics.blocks_authored,
+            &state.metrics.secondary_slots,
+            &state.metrics.secondary_slots_missed,
+        ] {
+            metric.remove(&validator_labels);
+        }
+        state
+            .metrics
+            .secondary_slot_miss_ratio
+            .remove(&validator_labels);
+        for metric in [
+            &state.metrics.blocks_authored_session,
+            &state.metrics.blocks_authored_era,
+            &state.metrics.last_authored_block_timestamp,
+            &state.metrics.epoch_secondary_slots,
+        ] {
+            metric.remove(&validator_labels);
+        }
+    }
+}
+
+// session validator set and era the authored block counts refer to
+#[derive(Default)]
+struct Authorship {
+    session: Option<u32>,
+    era: Option<Era>,
+    validators: Vec<AccountId32>,
+    epoch: Option<u64>,
+    // upcoming secondary slots of tracked validators, by slot
+    expected: BTreeMap<u64, usize>,
+    last_slot: Option<u64>,
+}
+
+// count the blocks authored by tracked validators and their missed secondary slots,
+// mapping the babe authority index through the session validator set
+async fn process_authorship(
+    state: &State,
+    rpc: &SubstrateRPC,
+    hash: H256,
+    authorship: &mut Authorship,
+) {
+    let Some(claim) = rpc.get_babe_claim(hash).await else {
+        return;
+    };
+    let at = rpc.at_block(hash);
+
+    let session = at.get_session_index().await;
+    if session != authorship.session || authorship.validators.is_empty() {
+        let Some(validators) = at.get_session_validators().await else {
+            return;
+        };
+        authorship.validators = validators;
+    }
+    if session != authorship.session {
+        authorship.session = session;
+        for validator in state.config.validators.iter() {
+            state
+                .metrics
+                .blocks_authored_session
+                .get_or_create(&validator_labels(&state.config, validator))
+                .set(0);
+        }
+    }
+    // eras are tracked on the staking chain, which may not be the relay chain
+    let era = match state.rpc.read().await.clone() {
+        Some(rpc) => rpc.get_current_era().await,
+        None => authorship.era,
+    };
+    if era != authorship.era {
+        authorship.era = era;
+        for validator in state.config.validators.iter() {
+            state
+                .metrics
+                .blocks_authored_era
+                .get_or_create(&validator_labels(&state.config, validator))
+                .set(0);
+        }
+    }
+
+    // tracked validators by authority index
+    let tracked: BTreeMap<usize, usize> = authorship
+        .validators
+        .iter()
+        .enumerate()
+        .filter_map(|(index, account_id)| {
+            let position = state.config.validators.iter().position(|v| {
+                v.address
+                    .parse::<AccountId32>()
+                    .is_ok_and(|id| id == *account_id)
+            })?;
+            Some((index, position))
+        })
+        .collect();
+
+    // secondary slots are assigned once per epoch, from the slots still to be processed
+    let epoch_index = at.get_epoch_index().await;
+    if epoch_index.is_some() && epoch_index != authorship.epoch {
+        if let Some(epoch) = at.get_babe_epoch().await {
+            let from = authorship.last_slot.map_or(claim.slot, |slot| slot + 1);
+            let expected =
+                slots::expected_secondary_slots(&epoch, |index| tracked.contains_key(&index));
+            for (position, validator) in state.config.validators.iter().enumerate() {
+                let count = expected
+                    .values()
+                    .filter(|index| tracked.get(index) == Some(&position))
+                    .count();
+                state
+                    .metrics
+                    .epoch_secondary_slots
+                    .get_or_create(&validator_labels(&state.config, validator))
+                    .set(count as i64);
+            }
+            authorship.expected.extend(
+                expected
+                    .range(from..)
+                    .map(|(slot, index)| (*slot, tracked[index])),
+            );
+            authorship.epoch = Some(epoch.index);
+        }
+    }
+
+    // expected slots without a finalized block were missed, a block by another author
+    // in an expected slot won it with a primary claim
+    let author = tracked.get(&(claim.authority_index as usize)).copied();
+    for (slot, position) in authorship.expected.range(..=claim.slot) {
+        let authored = *slot == claim.slot && author == Some(*position);
+        if *slot == claim.slot && !authored {
+            continue;
+        }
+        let validator_labels = validator_labels(&state.config, &state.config.validators[*position]);
+        let expected = state
+            .metrics
+            .secondary_slots
+            .get_or_create(&validator_labels)
+            .inc()
+            + 1;
+        if !authored {
+            state
+                .metrics
+                .secondary_slots_missed
+                .get_or_create(&validator_labels)
+                .inc();
+        }
+        let missed = state
+            .metrics
+            .secondary_slots_missed
+            .get_or_create(&validator_labels)
+            .get();
+        state
+            .metrics
+            .secondary_slot_miss_ratio
+            .get_or_create(&validator_labels)
+            .set(missed as f64 / expected as f64);
+    }
+    authorship.expected = authorship.expected.split_off(&(claim.slot + 1));
+    authorship.last_slot = Some(claim.slot);
+
+    let Some(position) = author else {
+        return;
+    };
+    let validator_labels = validator_labels(&state.config, &state.config.validators[position]);
+    state
+        .metrics
+        .blocks_authored
+        .get_or_create(&validator_labels)
+        .inc();
+    state
+        .metrics
+        .blocks_authored_session
+        .get_or_create(&validator_labels)
+        .inc();
+    state
+        .metrics
+        .blocks_authored_era
+        .get_or_create(&validator_labels)
+        .inc();
+    if let Some(timestamp) = at.get_timestamp().await {
+        state
+            .metrics
+            .last_authored_block_timestamp
+            .get_or_create(&validator_labels)
+            .set((timestamp / 1000) as i64);
+    }
+}
+
+// count and record the rewards paid out to tracked validators and their nominators
+async fn process_rewards(state: &State, number: u32, hash: H256, events: &Events<PolkadotConfig>) {
+    let tracked: Vec<(AccountId32, &Validator)> = state
+        .config
+        .validators
+        .iter()
+        .filter_map(|v| Some((v.address.parse::<AccountId32>().ok()?, v)))
+        .collect();
+
+    // rewarded events belong to the payout started earlier in the same extrinsic
+    let mut payout: Option<(u32, Era, &AccountId32, &Validator)> = None;
+    for event in events.iter().flatten() {
+        let Phase::ApplyExtrinsic(extrinsic_index) = event.phase() else {
+            continue;
+        };
+
+        if let Ok(Some(started)) = event.as_event::<PayoutStarted>() {
+            payout = tracked
+                .iter()
+                .find(|(id, _)| id == &started.validator_stash)
+                .map(|(id, v)| (extrinsic_index, started.era_index, id, *v));
+            if let Some((_, era, _, validator)) = payout {
+                state
+                    .metrics
+                    .payouts_started
+                    .get_or_create(&EraLabels {
+                        era,
+                        labels: validator_labels(&state.config, validator),
+                    })
+                    .inc();
+            }
+            continue;
+        }
+
+        let Ok(Some(rewarded)) = event.as_event::<Rewarded>() else {
+            continue;
+        };
+        let Some((_, era, validator_id, validator)) =
+            payout.filter(|(i, _, _, _)| *i == extrinsic_index)
+        else {
+            continue;
+        };
+
+        let stash = serialize_address(&state.config.network, &rewarded.stash);
+        let recipient = match &rewarded.stash == validator_id {
+            true => "validator",
+            false => "nominators",
+        };
+        let reward_labels = RewardLabels {
+            era,
+            recipient: recipient.into(),
+            labels: validator_labels(&state.config, validator),
+        };
+        state
+            .metrics
+            .rewards_received_planck
+            .get_or_create(&reward_labels)
+            .inc_by(rewarded.amount as f64);
+        state
+            .metrics
+            .rewards_received_tokens
+            .get_or_create(&reward_labels)
+            .inc_by(tokens_to_units(&state.config.network, rewarded.amount));
+        log::info!(
+            "Reward of {} paid to {stash} for {} at era {era} in block {number} extrinsic {extrinsic_index}",
+            rewarded.amount,
+            validator.name
+        );
+
+        if let Some(store) = state.store.as_ref() {
+            let reward = RewardEntry {
+                era,
+                stash,
+                amount: rewarded.amount,
+                block_number: number,
+                block_hash: format!("{hash:?}"),
+                extrinsic_index,
+                event_index: event.index(),
+            };
+            if let Err(e) = store.insert_reward(&validator.address, &reward) {
+                log::warn!("Unable to store reward in block {number}: {e}");
+            }
+        }
+    }
+}
+
+// Slashing worker, monitors slashes and offence reports involving the tracked validators
+pub async fn slashing_worker(state: State) {
+    // offender of every offence report seen, reports are never modified once stored
+    let mut offenders: BTreeMap<H256, Option<AccountId32>> = BTreeMap::new();
+    let mut published_kinds: BTreeMap<String, BTreeSet<String>> = BTreeMap::new();
+    let mut seen: BTreeSet<(String, String)> = BTreeSet::new();
+
+    loop {
+        if *state.shutdown.read().await {
+            log::info!("slashing_worker shutting down");
+            break;
+        }
+        let rpc_opt = state.rpc.read().await.clone();
+        let Some(rpc) = rpc_opt else {
+            // RPC DOWN → DROP SLASHING SERIES, zeros would read as no slashes
+            for validator in state.config.validators.iter() {
+                let labels = validator_labels(&state.config, validator);
+                for metric in [
+                    &state.metrics.pending_slashes,
+                    &state.metrics.pending_slash_amount,
+                    &state.metrics.slashed_amount,
+                    &state.metrics.slashing_span_index,
+                    &state.metrics.pending_slash_era,
+                    &state.metrics.last_slash_era,
+                ] {
+                    metric.remove(&labels);
+                }
+                for kind in published_kinds
+                    .remove(&validator.address)
+                    .unwrap_or_default()
+                {
+                    state.metrics.offences.remove(&KindLabels {
+                        kind,
+                        labels: labels.clone(),
+                    });
+                }
+            }
+
+            task::sleep(Duration::from_secs(SCRAPE_INTERVAL)).await;
+            continue;
+        };
+
+        // series of failed queries are dropped, zeros would read as no slashes
+        let unapplied = rpc.get_unapplied_slashes().await;
+        let slashes = rpc.get_validator_slashes().await;
+        // offences are reported on the relay chain
+        let relay_opt = relay_rpc(&state).await;
+        let reports = match relay_opt.as_ref() {
+            Some(relay) => relay.get_offence_reports().await,
+            None => None,
+        };
+        if let (Some(relay), Some(reports)) = (relay_opt.as_ref(), reports.as_ref()) {
+            for (_, report_id) in reports.iter() {
+                if !offenders.contains_key(report_id) {
+                    offenders.insert(*report_id, relay.get_offender(*report_id).await);
+                }
+            }
+        }
+
+        for validator in state.config.validators.iter() {
+            let labels = validator_labels(&state.config, validator);
+            let Ok(account_id) = validator.address.parse::<AccountId32>() else {
+                continue;
+            };
+            let mut record = |id: String, entry: SlashingEntry| {
+                record_slashing(&state, &mut seen, validator, id, entry)
+            };
+
+            // slashes deferred until the era they are applied at
+            match unapplied.as_ref() {
+                Some(unapplied) => {
+                    let pending: Vec<&(Era, UnappliedSlash)> = unapplied
+                        .iter()
+                        .filter(|(_, slash)| slash.validator == account_id)
+                        .collect();
+                    let mut pending_amount = 0;
+                    for (era, slash) in pending.iter() {
+                        let amount =
+                            slash.own + slash.others.iter().map(|(_, v)| v).sum::<Tokens>();
+                        pending_amount += amount;
+                        record(
+                            format!("unapplied/{era}/{amount}"),
+                            SlashingEntry {
+                                source: "Staking::UnappliedSlashes".into(),
+                                era: Some(*era),
+                                amount: Some(amount),
+                                detail: format!(
+                                    "{} own and {} nominators to be slashed",
+                                    slash.own,
+                                    slash.others.len()
+                                ),
+                            },
+                        );
+                    }
+                    state
+                        .metrics
+                        .pending_slashes
+                        .get_or_create(&labels)
+                        .set(pending.len() as i64);
+                    state
+                        .metrics
+                        .pending_slash_amount
+                        .get_or_create(&labels)
+                        .set(tokens_to_i64(pending_amount));
+                    match pending.iter().map(|(era, _)| *era).min() {
+                        Some(era) => {
+                            state
+                                .metrics
+                                .pending_slash_era
+                                .get_or_create(&labels)
+                                .set(era.into());
+                        }
+                        None => {
+                            state.metrics.pending_slash_era.remove(&labels);
+                        }
+                    }
+                }
+                None => {
+                    for metric in [
+                        &state.metrics.pending_slashes,
+                        &state.metrics.pending_slash_amount,
+                        &state.metrics.pending_slash_era,
+                    ] {
+                        metric.remove(&labels);
+                    }
+                }
+            }
+
+            // slashes applied within the history depth
+            match slashes.as_ref() {
+                Some(slashes) => {
+                    let mut slashed_amount = 0;
+                    for (era, _, amount) in slashes.iter().filter(|(_, id, _)| id == &account_id) {
+                        slashed_amount += amount;
+                        record(
+                            format!("slash/{era}"),
+                            SlashingEntry {
+                                source: "Staking::ValidatorSlashInEra".into(),
+                                era: Some(*era),
+                                amount: Some(*amount),
+                                detail: format!("slashed {amount} in era {era}"),
+                            },
+                        );
+                    }
+                    state
+                        .metrics
+                        .slashed_amount
+                        .get_or_create(&labels)
+                        .set(tokens_to_i64(slashed_amount));
+                }
+                None => {
+                    state.metrics.slashed_amount.remove(&labels);
+                }
+            }
+
+            // slashing spans only exist once a validator has been slashed
+            match rpc.get_slashing_spans(&account_id).await {
+                Some(Some(spans)) => {
+                    record(
+                        format!("span/{}", spans.span_index),
+                        SlashingEntry {
+                            source: "Staking::SlashingSpans".into(),
+                            era: Some(spans.last_nonzero_slash),
+                            amount: None,
+                            detail: format!(
+                                "span {} with {} prior spans",
+                                spans.span_index,
+                                spans.prior.len()
+                            ),
+                        },
+                    );
+                    state
+                        .metrics
+                        .slashing_span_index
+                        .get_or_create(&labels)
+                        .set(spans.span_index.into());
+                    state
+                        .metrics
+                        .last_slash_era
+                        .get_or_create(&labels)
+                        .set(spans.last_nonzero_slash.into());
+                }
+                Some(None) => {
+                    state
+                        .metrics
+                        .slashing_span_index
+                        .get_or_create(&labels)
+                        .set(0);
+                    state.metrics.last_slash_era.remove(&labels);
+                }
+                None => {
+                    state.metrics.slashing_span_index.remove(&labels);
+                    state.metrics.last_slash_era.remove(&labels);
+                }
+            }
+
+            // offence reports by kind, dropped when the relay chain reports are unavailable
+            let mut kinds: BTreeMap<String, i64> = BTreeMap::new();
+            for (kind, report_id) in reports.iter().flatten() {
+                if offenders.get(report_id) != Some(&Some(account_id.clone())) {
+                    continue;
+                }
+                *kinds.entry(kind.clone()).or_default() += 1;
+                record(
+                    format!("offence/{report_id:?}"),
+                    SlashingEntry {
+                        source: "Offences::Reports".into(),
+                        era: None,
+                        amount: None,
+                        detail: format!("{kind} offence reported in {report_id:?}"),
+                    },
+                );
+            }
+            let published = published_kinds
+                .entry(validator.address.clone())
+                .or_default();
+            for kind in published.iter().filter(|kind| !kinds.contains_key(*kind)) {
+                state.metrics.offences.remove(&KindLabels {
+                    kind: kind.clone(),
+                    labels: labels.clone(),
+                });
+            }
+            for (kind, count) in kinds.iter() {
+                state
+                    .metrics
+                    .offences
+                    .get_or_create(&KindLabels {
+                        kind: kind.clone(),
+                        labels: labels.clone(),
+                    })
+                    .set(*count);
+            }
+            *published = kinds.into_keys().collect();
+        }
+
+        task::sleep(Duration::from_secs(SLASHING_INTERVAL)).await;
+    }
+}
+
+// log and store the first occurrence of a tracked validator in slashing storage
+fn record_slashing(
+    state: &State,
+    seen: &mut BTreeSet<(String, String)>,
+    validator: &Validator,
+    id: String,
+    entry: SlashingEntry,
+) {
+    if !seen.insert((validator.address.clone(), id.clone())) {
+        return;
+    }
+    log::error!(
+        "Validator {} ({}) appears in {}: {}",
+        validator.name,
+        validator.address,
+        entry.source,
+        entry.detail
+    );
+
+    if let Some(store) = state.store.as_ref() {
+        if let Err(e) = store.insert_slashing(&validator.address, &id, &entry) {
+            log::warn!("Unable to store slashing record {id}: {e}");
+        }
+    }
+}
+
+// Session worker, tracks relay chain session state of the tracked validators
+pub async fn session_worker(state: State) {
+    let labels = chain_labels(&state.config);
+    let mut published_keys: BTreeMap<String, Vec<KeyLabels>> = BTreeMap::new();
+
+    loop {
+        if *state.shutdown.read().await {
+            log::info!("session_worker shutting down");
+            break;
+        }
+
+        // session state is read from the relay chain, the series are dropped while it is
+        // unavailable
+        let Some(rpc) = relay_rpc(&state).await else {
+            state.metrics.disabled_validators.remove(&labels);
+            for validator in state.config.validators.iter() {
+                let validator_labels = validator_labels(&state.config, validator);
+                state.metrics.disabled.remove(&validator_labels);
+                state.metrics.session_keys_pending.remove(&validator_labels);
+                state
+                    .metrics
+                    .session_keys_activation
+                    .remove(&validator_labels);
+            }
+            for key_labels in published_keys.values().flatten() {
+                state.metrics.session_key.remove(key_labels);
+            }
+            published_keys.clear();
+
+            task::sleep(Duration::from_secs(SCRAPE_INTERVAL)).await;
+            continue;
+        };
+
+        let session_validators = rpc.get_session_validators().await;
+
+        // disabled validators are referenced by their index in the session validator set
+        if let (Some(session_validators), Some(disabled)) = (
+            session_validators.as_ref(),
+            rpc.get_disabled_validators().await,
+        ) {
+            let disabled: BTreeSet<&AccountId32> = disabled
+                .iter()
+                .filter_map(|index| session_validators.get(*index as usize))
+                .collect();
+            state
+                .metrics
+                .disabled_validators
+                .get_or_create(&labels)
+                .set(disabled.len() as i64);
+
+            for validator in state.config.validators.iter() {
+                let is_disabled = validator
+                    .address
+                    .parse::<AccountId32>()
+                    .is_ok_and(|account_id| disabled.contains(&account_id));
+                state
+                    .metrics
+                    .disabled
+                    .get_or_create(&validator_labels(&state.config, validator))
+                    .set(is_disabled.into());
+            }
+        } else {
+            state.metrics.disabled_validators.remove(&labels);
+            for validator in state.config.validators.iter() {
+                state
+                    .metrics
+                    .disabled
+                    .remove(&validator_labels(&state.config, validator));
+            }
+        }
+
+        // keys set now are queued at the end of this session and active in the one after,
+        // queued keys become active in the next session
+        let session_index = rpc.get_session_index().await;
+        let queued = rpc.get_queued_keys().await.unwrap_or_default();
+        let authorities = rpc.get_babe_authorities().await.unwrap_or_default();
+
+        for validator in state.config.validators.iter() {
+            let validator_labels = validator_labels(&state.config, validator);
+            let Ok(account_id) = validator.address.parse::<AccountId32>() else {
+                continue;
+            };
+            let next = rpc.get_next_keys(&account_id).await;
+
+            let keys: Vec<KeyLabels> = next
+                .iter()
+                .flatten()
+                .map(|(key_type, public_key)| KeyLabels {
+                    key_type: key_type.clone(),
+                    public_key: format_hex(public_key),
+                    labels: validator_labels.clone(),
+                })
+                .collect();
+            let published = published_keys.entry(validator.address.clone()).or_default();
+            for key_labels in published.iter().filter(|l| !keys.contains(l)) {
+                state.metrics.session_key.remove(key_labels);
+            }
+            for key_labels in keys.iter() {
+                state.metrics.session_key.get_or_create(key_labels).set(1);
+            }
+            *published = keys;
+
+            // the active babe key is the only one readable on-chain, at the session validator index
+            let queued_keys = queued
+                .iter()
+                .find(|(id, _)| *id == account_id)
+                .map(|(_, keys)| keys);
+            let active_babe = session_validators
+                .iter()
+                .flatten()
+                .position(|id| *id == account_id)
+                .and_then(|index| authorities.get(index));
+            let activation = match (next.as_ref(), queued_keys, session_index) {
+                (Some(next), Some(queued), Some(index)) if queued != next => Some(index + 2),
+                (_, Some(queued), Some(index))
+                    if active_babe.is_some_and(|active| queued.get("babe") != Some(active)) =>
+                {
+                    Some(index + 1)
+                }
+                _ => None,
+            };
+
+            state
+                .metrics
+                .session_keys_pending
+                .get_or_create(&validator_labels)
+                .set(activation.is_some().into());
+            match activation {
+                Some(activation) => {
+                    state
+                        .metrics
+                        .session_keys_activation
+                        .get_or_create(&validator_labels)
+                        .set(activation.into());
+                }
+                None => {
+                    state
+                        .metrics
+                        .session_keys_activation
+                        .remove(&validator_labels);
+                }
+            }
+        }
+
+        task::sleep(Duration::from_secs(SCRAPE_INTERVAL)).await;
     }
 }
 
-// Chain-level metrics worker
-pub async fn chain_metrics_worker(state: State) {
-    let labels = Labels {
-        network: serde_yaml::to_string(&state.config.network)
-            .unwrap()
-            .trim()
-            .into(),
-        chain: state.config.chain.clone(),
-        validator_name: None,
-        validator_address: None,
-    };
-    // let rpc = wait_for_rpc(&state).await;
+// Node worker, checks the validators' own nodes against the reference rpc
+pub async fn node_worker(state: State) {
+    let mut nodes: BTreeMap<String, NodeRPC> = BTreeMap::new();
+    let mut versions: BTreeMap<String, VersionLabels> = BTreeMap::new();
+
     loop {
         if *state.shutdown.read().await {
-            log::info!("chain_metrics_worker shutting down");
+            log::info!("node_worker shutting down");
             break;
         }
-        let rpc_opt = state.rpc.read().await.clone();
-        match rpc_opt {
-            Some(rpc) => {
-                if let Some(mut era) = rpc.get_current_era().await {
-                    state.metrics.era.get_or_create(&labels).set(era.into());
-                    let era_points = match rpc.get_all_era_points(era).await {
-                        Some(res) => res,
-                        // if fetching from current era fails, try fetching from previous era
-                        None => match rpc.get_all_era_points(era - 1).await {
-                            Some(res) => {
-                                era -= 1;
-                                res
-                            }
-                            None => EraPointsMap::default(),
-                        },
-                    };
+        // lag is measured against the best block of the staking or relay chain rpc,
+        // whichever runs the same chain as the node
+        let relay_opt = relay_rpc(&state).await;
+        let mut references = vec![];
+        for rpc in [state.rpc.read().await.clone(), relay_opt.clone()]
+            .into_iter()
+            .flatten()
+        {
+            if let (Some(chain), Some(block)) = (
+                rpc.get_chain_name().await,
+                rpc.get_best_block_number().await,
+            ) {
+                references.push((chain, block));
+            }
+        }
 
-                    let active_count = era_points.individual.len() as i64;
+        for validator in state.config.validators.iter() {
+            let Some(url) = validator.node_rpc_url.as_ref() else {
+                continue;
+            };
+            let validator_labels = validator_labels(&state.config, validator);
 
-                    if let Some(min) = rpc.get_minimum_active_stake().await {
-                        state
-                            .metrics
-                            .minimum_active_stake
-                            .get_or_create(&labels)
-                            .set(tokens_to_i64(min));
+            if !nodes.contains_key(&validator.address) {
+                match NodeRPC::new(url).await {
+                    Ok(node) => {
+                        nodes.insert(validator.address.clone(), node);
                     }
+                    Err(e) => log::warn!("Unable to connect to node {url}: {e}"),
+                }
+            }
+            let health = match nodes.get(&validator.address) {
+                Some(node) => node.get_health().await,
+                None => None,
+            };
+            let (Some(node), Some(health)) = (nodes.get(&validator.address), health) else {
+                // NODE DOWN → RESET TO DEFAULTS
+                nodes.remove(&validator.address);
+                for metric in [
+                    &state.metrics.node_rpc_health,
+                    &state.metrics.node_peers,
+                    &state.metrics.node_syncing,
+                ] {
+                    metric.get_or_create(&validator_labels).set(0);
+                }
+                state
+                    .metrics
+                    .node_has_session_keys
+                    .remove(&validator_labels);
+                state.metrics.node_best_block.remove(&validator_labels);
+                state.metrics.node_block_lag.remove(&validator_labels);
+                if let Some(version_labels) = versions.remove(&validator.address) {
+                    state.metrics.node_version.remove(&version_labels);
+                }
+                continue;
+            };
 
-                    if let Some(total) = rpc.get_total_stake(era).await {
-                        if active_count > 0 {
+            state
+                .metrics
+                .node_rpc_health
+                .get_or_create(&validator_labels)
+                .set(1);
+            state
+                .metrics
+                .node_peers
+                .get_or_create(&validator_labels)
+                .set(health.peers as i64);
+            state
+                .metrics
+                .node_syncing
+                .get_or_create(&validator_labels)
+                .set(health.is_syncing.into());
+
+            let chain = node.get_chain().await;
+            if let (Some(version), Some(chain)) = (node.get_version().await, chain.clone()) {
+                let version_labels = VersionLabels {
+                    version,
+                    chain,
+                    labels: validator_labels.clone(),
+                };
+                if let Some(previous) =
+                    versions.insert(validator.address.clone(), version_labels.clone())
+                {
+                    if previous != version_labels {
+                        state.metrics.node_version.remove(&previous);
+                    }
+                }
+                state
+                    .metrics
+                    .node_version
+                    .get_or_create(&version_labels)
+                    .set(1);
+            }
+
+            match node.get_sync_state().await {
+                Some(sync_state) => {
+                    state
+                        .metrics
+                        .node_best_block
+                        .get_or_create(&validator_labels)
+                        .set(sync_state.current_block.into());
+                    // block numbers are only comparable when the node runs the reference chain
+                    let reference_block = references
+                        .iter()
+                        .find(|(reference_chain, _)| Some(reference_chain) == chain.as_ref())
+                        .map(|(_, block)| *block);
+                    match reference_block {
+                        Some(reference_block) => {
                             state
                                 .metrics
-                                .average_stake
-                                .get_or_create(&labels)
-                                .set(tokens_to_i64(total) / active_count);
+                                .node_block_lag
+                                .get_or_create(&validator_labels)
+                                .set(reference_block as i64 - sync_state.current_block as i64);
+                        }
+                        None => {
+                            state.metrics.node_block_lag.remove(&validator_labels);
                         }
                     }
                 }
+                None => {
+                    state.metrics.node_best_block.remove(&validator_labels);
+                    state.metrics.node_block_lag.remove(&validator_labels);
+                }
             }
-            None => {
-                // RPC DOWN → RESET TO DEFAULTS
-                state.metrics.era.get_or_create(&labels).set(0);
+
+            // keys are registered on the relay chain, without an on-chain registration they
+            // count as not held; the series is dropped when the check is unavailable, such as
+            // on nodes without unsafe rpc methods
+            let next_keys = match (relay_opt.as_ref(), validator.address.parse::<AccountId32>()) {
+                (Some(relay), Ok(account_id)) => relay.get_encoded_next_keys(&account_id).await,
+                _ => None,
+            };
+            let has_session_keys = match next_keys {
+                Some(Some(next_keys)) => node.has_session_keys(next_keys).await,
+                Some(None) => Some(false),
+                None => None,
+            };
+            match has_session_keys {
+                Some(has_session_keys) => {
+                    state
+                        .metrics
+                        .node_has_session_keys
+                        .get_or_create(&validator_labels)
+                        .set(has_session_keys.into());
+                }
+                None => {
+                    state
+                        .metrics
+                        .node_has_session_keys
+                        .remove(&validator_labels);
+                }
+            }
+        }
+
+        task::sleep(Duration::from_secs(NODE_INTERVAL)).await;
+    }
+}
+
+// Grandpa worker, checks the finality votes of the tracked validators in the rounds
+// reported by the configured node
+pub async fn grandpa_worker(state: State, url: String) {
+    let mut node: Option<NodeRPC> = None;
+    // grandpa keys of the tracked validators and the session they were fetched in
+    let mut keys: BTreeMap<String, BTreeSet<Vec<u8>>> = BTreeMap::new();
+    let mut active: BTreeSet<String> = BTreeSet::new();
+    let mut keys_session = None;
+    let mut keys_set_id = None;
+    // missing prevoters and precommitters last reported per (set id, round)
+    let mut rounds: BTreeMap<(u64, u64), [BTreeSet<Vec<u8>>; 2]> = BTreeMap::new();
+
+    let missing = |votes: &RoundVotes| -> BTreeSet<Vec<u8>> {
+        votes
+            .missing
+            .iter()
+            .filter_map(|id| id.parse::<AccountId32>().ok())
+            .map(|id| id.0.to_vec())
+            .collect()
+    };
+
+    loop {
+        if *state.shutdown.read().await {
+            log::info!("grandpa_worker shutting down");
+            break;
+        }
+        // grandpa keys are registered on the relay chain, the series are dropped while
+        // no babe relay chain is reachable
+        let Some(rpc) = relay_rpc(&state).await else {
+            rounds.clear();
+            keys.clear();
+            keys_session = None;
+            keys_set_id = None;
+            for validator in state.config.validators.iter() {
+                let validator_labels = validator_labels(&state.config, validator);
+                state.metrics.grandpa_voting.remove(&validator_labels);
+                for kind in ["prevote", "precommit"] {
+                    state.metrics.grandpa_rounds_missed.remove(&KindLabels {
+                        kind: kind.into(),
+                        labels: validator_labels.clone(),
+                    });
+                }
+            }
+
+            task::sleep(Duration::from_secs(SCRAPE_INTERVAL)).await;
+            continue;
+        };
+        for validator in state.config.validators.iter() {
+            for kind in ["prevote", "precommit"] {
                 state
                     .metrics
-                    .minimum_active_stake
-                    .get_or_create(&labels)
+                    .grandpa_rounds_missed
+                    .get_or_create(&KindLabels {
+                        kind: kind.into(),
+                        labels: validator_labels(&state.config, validator),
+                    })
+                    .inc_by(0);
+            }
+        }
+
+        if node.is_none() {
+            match NodeRPC::new(&url).await {
+                Ok(client) => node = Some(client),
+                Err(e) => log::warn!("Unable to connect to node {url}: {e}"),
+            }
+        }
+        let round_state = match node.as_ref() {
+            Some(node) => node.get_round_state().await,
+            None => None,
+        };
+        if round_state.is_none() {
+            node = None;
+        }
+        let Some(round_state) = round_state else {
+            // RPC DOWN → RESET TO DEFAULTS
+            rounds.clear();
+            for validator in state.config.validators.iter() {
+                state
+                    .metrics
+                    .grandpa_voting
+                    .get_or_create(&validator_labels(&state.config, validator))
                     .set(0);
-                state.metrics.average_stake.get_or_create(&labels).set(0);
+            }
+
+            task::sleep(Duration::from_secs(SCRAPE_INTERVAL)).await;
+            continue;
+        };
+
+        // keys set now are only used from a later session, so both next and queued keys count,
+        // next to the key of the current authority set that a rotation already replaced
+        let session = rpc.get_session_index().await;
+        if session != keys_session || Some(round_state.set_id) != keys_set_id || keys.is_empty() {
+            let queued = rpc.get_queued_keys().await.unwrap_or_default();
+            let session_validators = rpc.get_session_validators().await.unwrap_or_default();
+            let authorities = rpc
+                .get_grandpa_authorities()
+                .await
+                .filter(|authorities| authorities.len() == session_validators.len())
+                .unwrap_or_default();
+            keys.clear();
+            active.clear();
+            for validator in state.config.validators.iter() {
+                let Ok(account_id) = validator.address.parse::<AccountId32>() else {
+                    continue;
+                };
+                let mut grandpa_keys = BTreeSet::new();
+                if let Some(key) = rpc
+                    .get_next_keys(&account_id)
+                    .await
+                    .and_then(|keys| keys.get("grandpa").cloned())
+                {
+                    grandpa_keys.insert(key);
+                }
+                if let Some(key) = queued
+                    .iter()
+                    .find(|(id, _)| *id == account_id)
+                    .and_then(|(_, keys)| keys.get("grandpa").cloned())
+                {
+                    grandpa_keys.insert(key);
+                }
+                if let Some(key) = session_validators
+                    .iter()
+                    .position(|id| *id == account_id)
+                    .and_then(|index| authorities.get(index).cloned())
+                {
+                    grandpa_keys.insert(key);
+                }
+                keys.insert(validator.address.clone(), grandpa_keys);
+                if session_validators.contains(&account_id) {
+                    active.insert(validator.address.clone());
+                }
+            }
+            keys_session = session;
+            keys_set_id = Some(round_state.set_id);
+        }
+
+        let reported: BTreeMap<(u64, u64), [BTreeSet<Vec<u8>>; 2]> =
+            std::iter::once(&round_state.best)
+                .chain(round_state.background.iter())
+                .map(|round| {
+                    (
+                        (round_state.set_id, round.round),
+                        [missing(&round.prevotes), missing(&round.precommits)],
+                    )
+                })
+                .collect();
+
+        // rounds no longer reported are completed, voters still missing in their last
+        // report missed them
+        for (_, votes) in rounds
+            .iter()
+            .filter(|(round, _)| !reported.contains_key(*round))
+        {
+            for validator in state.config.validators.iter() {
+                let Some(validator_keys) = keys.get(&validator.address) else {
+                    continue;
+                };
+                for (kind, missing) in ["prevote", "precommit"].iter().zip(votes.iter()) {
+                    if validator_keys.is_disjoint(missing) {
+                        continue;
+                    }
+                    state
+                        .metrics
+                        .grandpa_rounds_missed
+                        .get_or_create(&KindLabels {
+                            kind: kind.to_string(),
+                            labels: validator_labels(&state.config, validator),
+                        })
+                        .inc();
+                }
             }
         }
 
+        let best_prevotes = missing(&round_state.best.prevotes);
+        for validator in state.config.validators.iter() {
+            let voting = active.contains(&validator.address)
+                && keys
+                    .get(&validator.address)
+                    .is_some_and(|keys| keys.is_disjoint(&best_prevotes));
+            state
+                .metrics
+                .grandpa_voting
+                .get_or_create(&validator_labels(&state.config, validator))
+                .set(voting.into());
+        }
+        rounds = reported;
+
+        task::sleep(Duration::from_secs(SCRAPE_INTERVAL)).await;
+    }
+}
+
+// Election prediction worker, runs phragmen locally over each new election snapshot
+pub async fn election_prediction_worker(state: State) {
+    let labels = chain_labels(&state.config);
+    let mut predicted_round = None;
+
+    loop {
+        if *state.shutdown.read().await {
+            log::info!("election_prediction_worker shutting down");
+            break;
+        }
+        let rpc_opt = state.rpc.read().await.clone();
+        let round = match rpc_opt.as_ref() {
+            Some(rpc) => rpc.get_election_round().await,
+            None => None,
+        };
+
+        // the snapshot is only fetched once per election round
+        if round.is_some() && round == predicted_round {
+            task::sleep(Duration::from_secs(SCRAPE_INTERVAL)).await;
+            continue;
+        }
+
+        let snapshot = match rpc_opt.as_ref() {
+            Some(rpc) if round.is_some() => rpc.get_election_snapshot().await,
+            _ => None,
+        };
+
+        let (Some(rpc), Some(snapshot)) = (rpc_opt, snapshot) else {
+            // NO SNAPSHOT → DROP STALE PREDICTIONS
+            state
+                .metrics
+                .election_snapshot_voters
+                .get_or_create(&labels)
+                .set(0);
+            state
+                .metrics
+                .election_snapshot_targets
+                .get_or_create(&labels)
+                .set(0);
+            for validator in state.config.validators.iter() {
+                let validator_labels = validator_labels(&state.config, validator);
+                state.metrics.predicted_elected.remove(&validator_labels);
+                state.metrics.predicted_backing.remove(&validator_labels);
+            }
+
+            task::sleep(Duration::from_secs(SCRAPE_INTERVAL)).await;
+            continue;
+        };
+
+        state
+            .metrics
+            .election_snapshot_voters
+            .get_or_create(&labels)
+            .set(snapshot.voters.len() as i64);
+        state
+            .metrics
+            .election_snapshot_targets
+            .get_or_create(&labels)
+            .set(snapshot.targets.len() as i64);
+
+        // vote weights are scaled down from balances when the issuance exceeds u64
+        let total_issuance = rpc.get_total_issuance().await.unwrap_or_default();
+        let factor = (total_issuance / u64::MAX as i128).max(1);
+
+        let round = snapshot.round;
+        let elected = task::spawn_blocking(move || {
+            phragmen::elect(
+                &snapshot.voters,
+                &snapshot.targets,
+                snapshot.desired_targets as usize,
+            )
+        })
+        .await;
+        log::info!(
+            "Predicted election for round {round}: {} elected",
+            elected.len()
+        );
+
+        for validator in state.config.validators.iter() {
+            let validator_labels = validator_labels(&state.config, validator);
+            let Ok(account_id) = validator.address.parse::<AccountId32>() else {
+                continue;
+            };
+
+            let backing = elected
+                .iter()
+                .find(|(id, _)| id == &account_id)
+                .map(|(_, backing)| *backing as Tokens * factor);
+
+            state
+                .metrics
+                .predicted_elected
+                .get_or_create(&validator_labels)
+                .set(backing.is_some().into());
+            state
+                .metrics
+                .predicted_backing
+                .get_or_create(&validator_labels)
+                .set(tokens_to_i64(backing.unwrap_or_default()));
+        }
+
+        predicted_round = Some(round);
+
         task::sleep(Duration::from_secs(SCRAPE_INTERVAL)).await;
     }
 }
@@ -110,6 +2173,19 @@ pub async fn validator_metrics_worker(state: State, validator: Validator) {
         validator_address: Some(validator.address.clone()),
     };
 
+    // number of exposure pages published in the previous iteration
+    let mut published_pages = 0;
+
+    // last completed era whose results have been published
+    let mut finalized_era = None;
+
+    // (block number, timestamp, era points) samples of the active era within the rate window
+    let mut samples: VecDeque<(u32, u64, i64)> = VecDeque::new();
+    let mut sampled_era = None;
+
+    // era points at the start of the current session, unknown until a session boundary is seen
+    let mut session_start: Option<(u32, Option<i64>)> = None;
+
     loop {
         if *state.shutdown.read().await {
             log::info!("chain_metrics_worker shutting down");
@@ -122,8 +2198,55 @@ pub async fn validator_metrics_worker(state: State, validator: Validator) {
             // RPC DOWN → RESET ALL METRICS TO DEFAULTS
             state.metrics.active.get_or_create(&labels).set(0);
             state.metrics.era_points.get_or_create(&labels).set(0);
+            state.metrics.era_points_percentile.remove(&labels);
+            state.metrics.era_points_median_ratio.remove(&labels);
+            state.metrics.era_points_mean_ratio.remove(&labels);
+            state.metrics.era_points_zscore.remove(&labels);
+            state.metrics.era_points_per_hour.remove(&labels);
+            state.metrics.era_points_per_block.remove(&labels);
+            state.metrics.session_era_points.remove(&labels);
+            state.metrics.projected_era_points.remove(&labels);
+            samples.clear();
+            session_start = None;
             state.metrics.nominator_stake.get_or_create(&labels).set(0);
             state.metrics.nominator_count.get_or_create(&labels).set(0);
+            state.metrics.own_stake.get_or_create(&labels).set(0);
+            state
+                .metrics
+                .exposure_page_count
+                .get_or_create(&labels)
+                .set(0);
+            state
+                .metrics
+                .nominators_over_page_size
+                .get_or_create(&labels)
+                .set(0);
+            state.metrics.oversubscribed.get_or_create(&labels).set(0);
+            state.metrics.next_era_active.remove(&labels);
+            state.metrics.next_era_backing.remove(&labels);
+            for backing in ["active", "inactive"] {
+                let backing_labels = BackingLabels {
+                    backing: backing.into(),
+                    labels: labels.clone(),
+                };
+                state
+                    .metrics
+                    .nominations_count
+                    .get_or_create(&backing_labels)
+                    .set(0);
+                state
+                    .metrics
+                    .nominations_stake
+                    .get_or_create(&backing_labels)
+                    .set(0);
+            }
+            for page in 0..published_pages {
+                state.metrics.exposure_page_stake.remove(&PageLabels {
+                    labels: labels.clone(),
+                    page,
+                });
+            }
+            published_pages = 0;
 
             task::sleep(Duration::from_secs(SCRAPE_INTERVAL)).await;
             continue;
@@ -133,6 +2256,47 @@ pub async fn validator_metrics_worker(state: State, validator: Validator) {
         // determine active era
         let active_era = rpc.get_current_era().await.unwrap_or(0);
 
+        // publish the final results of the previous era once it completes,
+        // these are kept as is while the RPC is down
+        let completed_era = active_era.checked_sub(1);
+        if let Some(era) = completed_era.filter(|era| Some(*era) != finalized_era) {
+            let points = rpc.get_all_era_points(era).await;
+            let summary = rpc.get_nominator_summary(era, &account_id).await;
+
+            if let Some(points) = points {
+                let (active, points) = points
+                    .individual
+                    .iter()
+                    .find(|(id, _)| id == &account_id)
+                    .map(|(_, p)| (true, *p))
+                    .unwrap_or_default();
+                let stake = summary.unwrap_or_default().total;
+
+                state
+                    .metrics
+                    .active_last_era
+                    .get_or_create(&labels)
+                    .set(active.into());
+                state
+                    .metrics
+                    .era_points_last_era
+                    .get_or_create(&labels)
+                    .set(points);
+                state
+                    .metrics
+                    .nominator_stake_last_era
+                    .get_or_create(&labels)
+                    .set(tokens_to_i64(stake));
+
+                log::info!(
+                    "Era {era} finished for {} ({}): active={active}, era_points={points}, nominator_stake={stake}",
+                    validator.name,
+                    validator.address
+                );
+                finalized_era = Some(era);
+            }
+        }
+
         // find a finalized era using nominator data
 
         let mut effective_era = None;
@@ -150,10 +2314,12 @@ pub async fn validator_metrics_worker(state: State, validator: Validator) {
 
         let mut active = false;
         let mut points = 0;
+        let mut all_points = vec![];
 
         if let Some(effective) = effective_era {
             if let Some(points_era) = effective.checked_sub(0) {
                 if let Some(map) = rpc.get_all_era_points(points_era).await {
+                    all_points = map.individual.iter().map(|(_, p)| *p as f64).collect();
                     if let Some((_, p)) = map.individual.iter().find(|(id, _)| id == &account_id) {
                         active = true;
                         points = *p;
@@ -171,6 +2337,125 @@ pub async fn validator_metrics_worker(state: State, validator: Validator) {
 
         state.metrics.era_points.get_or_create(&labels).set(points);
 
+        // publish era points relative to the active set
+        if active {
+            let points = points as f64;
+            all_points.sort_by(f64::total_cmp);
+            let std_dev = stats::std_dev(&all_points);
+            let zscore = if std_dev > 0.0 {
+                (points - stats::mean(&all_points)) / std_dev
+            } else {
+                0.0
+            };
+
+            state
+                .metrics
+                .era_points_percentile
+                .get_or_create(&labels)
+                .set(stats::percentile_rank(&all_points, points));
+            state
+                .metrics
+                .era_points_median_ratio
+                .get_or_create(&labels)
+                .set(stats::ratio(points, stats::median(&all_points)));
+            state
+                .metrics
+                .era_points_mean_ratio
+                .get_or_create(&labels)
+                .set(stats::ratio(points, stats::mean(&all_points)));
+            state
+                .metrics
+                .era_points_zscore
+                .get_or_create(&labels)
+                .set(zscore);
+        } else {
+            state.metrics.era_points_percentile.remove(&labels);
+            state.metrics.era_points_median_ratio.remove(&labels);
+            state.metrics.era_points_mean_ratio.remove(&labels);
+            state.metrics.era_points_zscore.remove(&labels);
+        }
+
+        // publish era point rates of the active era
+        let progress = rpc
+            .get_era_progress(relay_rpc(&state).await.as_deref())
+            .await;
+        let block_number = rpc.get_block_number().await;
+        match (progress, block_number) {
+            (Some(progress), Some(block_number))
+                if active && effective_era == Some(progress.era) =>
+            {
+                if sampled_era != Some(progress.era) {
+                    samples.clear();
+                    session_start = None;
+                    sampled_era = Some(progress.era);
+                }
+                if samples.back().map(|(b, _, _)| *b) != Some(block_number) {
+                    samples.push_back((block_number, progress.now, points));
+                }
+                while samples
+                    .front()
+                    .is_some_and(|(_, t, _)| progress.now.saturating_sub(*t) > RATE_WINDOW)
+                {
+                    samples.pop_front();
+                }
+
+                if let (Some(first), Some(last)) = (samples.front(), samples.back()) {
+                    let rate = stats::per_hour((last.2 - first.2) as f64, last.1 - first.1);
+                    state
+                        .metrics
+                        .era_points_per_hour
+                        .get_or_create(&labels)
+                        .set(rate);
+                    state
+                        .metrics
+                        .era_points_per_block
+                        .get_or_create(&labels)
+                        .set(stats::ratio(
+                            (last.2 - first.2) as f64,
+                            last.0.saturating_sub(first.0) as f64,
+                        ));
+                    if let Some(remaining) = progress.remaining {
+                        state
+                            .metrics
+                            .projected_era_points
+                            .get_or_create(&labels)
+                            .set(points as f64 + rate * stats::hours(remaining));
+                    }
+                }
+
+                if let Some(session_index) = progress.session_index {
+                    if session_start.map(|(s, _)| s) != Some(session_index) {
+                        // the points at session start are only known once a session boundary
+                        // was seen, or in the first session of the era when they are zero
+                        let start_points = match (session_start, progress.session_in_era) {
+                            (Some(_), _) => Some(points),
+                            (None, Some(0)) => Some(0),
+                            (None, _) => None,
+                        };
+                        session_start = Some((session_index, start_points));
+                    }
+                }
+                match session_start.and_then(|(_, start_points)| start_points) {
+                    Some(start_points) if progress.session_index.is_some() => {
+                        state
+                            .metrics
+                            .session_era_points
+                            .get_or_create(&labels)
+                            .set(points - start_points);
+                    }
+                    _ => {
+                        state.metrics.session_era_points.remove(&labels);
+                    }
+                }
+            }
+            _ => {
+                state.metrics.era_points_per_hour.remove(&labels);
+                state.metrics.era_points_per_block.remove(&labels);
+                state.metrics.session_era_points.remove(&labels);
+                state.metrics.projected_era_points.remove(&labels);
+            }
+        }
+
         // publish nominator metrics
         let summary = if let Some(era) = effective_era {
             rpc.get_nominator_summary(era, &account_id)
@@ -192,6 +2477,118 @@ pub async fn validator_metrics_worker(state: State, validator: Validator) {
             .get_or_create(&labels)
             .set(summary.nominator_count.into());
 
+        state
+            .metrics
+            .own_stake
+            .get_or_create(&labels)
+            .set(tokens_to_i64(summary.own));
+
+        // publish paged exposure metrics
+        state
+            .metrics
+            .exposure_page_count
+            .get_or_create(&labels)
+            .set(summary.page_count.into());
+
+        state
+            .metrics
+            .oversubscribed
+            .get_or_create(&labels)
+            .set((summary.page_count > 1).into());
+
+        if let Some(page_size) = rpc.get_max_exposure_page_size() {
+            state
+                .metrics
+                .nominators_over_page_size
+                .get_or_create(&labels)
+                .set(summary.nominator_count.saturating_sub(page_size).into());
+        }
+
+        // nominators that were assigned stake on the validator
+        let mut backers = BTreeSet::new();
+
+        if let Some(era) = effective_era {
+            for page in 0..summary.page_count {
+                if let Some(exposure) = rpc.get_exposure_page(era, &account_id, page).await {
+                    backers.extend(exposure.others.iter().map(|e| e.who.clone()));
+                    state
+                        .metrics
+                        .exposure_page_stake
+                        .get_or_create(&PageLabels {
+                            labels: labels.clone(),
+                            page,
+                        })
+                        .set(tokens_to_i64(exposure.page_total));
+                }
+            }
+        }
+
+        // drop pages that no longer exist
+        for page in summary.page_count..published_pages {
+            state.metrics.exposure_page_stake.remove(&PageLabels {
+                labels: labels.clone(),
+                page,
+            });
+        }
+        published_pages = summary.page_count;
+
+        // publish next era metrics once the next era is planned, dropping them on query errors
+        let planned_era = rpc.get_planned_era().await.unwrap_or(0);
+        let next = match planned_era > active_era {
+            true => rpc.get_elected_summary(planned_era, &account_id).await,
+            false => None,
+        };
+        if let Some(next) = next {
+            state
+                .metrics
+                .next_era_active
+                .get_or_create(&labels)
+                .set(next.is_some().into());
+            state
+                .metrics
+                .next_era_backing
+                .get_or_create(&labels)
+                .set(tokens_to_i64(next.unwrap_or_default().total));
+        } else {
+            state.metrics.next_era_active.remove(&labels);
+            state.metrics.next_era_backing.remove(&labels);
+        }
+
+        // publish nomination intent metrics
+        if let Some((_, index)) = state.nominations.read().await.as_ref() {
+            let (mut active_count, mut active_stake) = (0, 0);
+            let (mut inactive_count, mut inactive_stake) = (0, 0);
+            for (nominator, stake) in index.get(&account_id).into_iter().flatten() {
+                if backers.contains(nominator) {
+                    active_count += 1;
+                    active_stake += stake;
+                } else {
+                    inactive_count += 1;
+                    inactive_stake += stake;
+                }
+            }
+
+            for (backing, count, stake) in [
+                ("active", active_count, active_stake),
+                ("inactive", inactive_count, inactive_stake),
+            ] {
+                let backing_labels = BackingLabels {
+                    backing: backing.into(),
+                    labels: labels.clone(),
+                };
+                state
+                    .metrics
+                    .nominations_count
+                    .get_or_create(&backing_labels)
+                    .set(count);
+                state
+                    .metrics
+                    .nominations_stake
+                    .get_or_create(&backing_labels)
+                    .set(tokens_to_i64(stake));
+            }
+        }
+
         task::sleep(Duration::from_secs(SCRAPE_INTERVAL)).await;
     }
 }